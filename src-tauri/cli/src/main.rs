@@ -0,0 +1,511 @@
+//! A command line interface for the launcher, used for scripting and smoke tests
+//!
+//! It shares the settings and installations with the gui, so both can be used side by side.
+//! Every command which talks to the servers authenticates with the UID of the last login again,
+//! sessions are not kept between invocations. This invalidates the session of a running gui,
+//! which renews its session once the servers reject it instead of signing the user out
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use moon_launcher_core::api::moon::auth::{AuthenticationResponseData, Channel};
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::downloads::DownloadService;
+use moon_launcher_core::game::launch::{launch_selection, LaunchOptions};
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::game::version::ResolvedVersion;
+use moon_launcher_core::session::{SessionService, SessionStore};
+use moon_launcher_core::settings::{SettingsPatch, SettingsService};
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{
+    InterfaceSettingData, LoginSettingData, VersionSelectionData, VersionSettingData,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "moon-launcher-cli",
+    version,
+    about = "Runs the Moon launcher without its gui"
+)]
+struct Cli {
+    /// Prints JSON instead of human-readable output
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Signs in and remembers the UID for every other command
+    Login { uid: i64 },
+    /// Lists all channels you have access to
+    Channels,
+    /// Lists all versions of a channel
+    Versions { channel: String },
+    /// Installs the selected version of a channel, or the given one
+    Install {
+        channel: String,
+        #[arg(long)]
+        version: Option<String>,
+    },
+    /// Launches the selected version of a channel, installing it first if necessary
+    ///
+    /// The game keeps running once this command has exited, post-exit hooks are only run
+    /// while the launcher itself is still open
+    Launch {
+        channel: String,
+        /// Launches an installed version without signing in, like the gui does without a serial
+        #[arg(long)]
+        offline: bool,
+    },
+    /// Checks every settings file and installed version, exits with an error if any is broken
+    Verify,
+    /// Reads or changes settings
+    #[command(subcommand)]
+    Settings(SettingsCommand),
+}
+
+#[derive(Subcommand)]
+enum SettingsCommand {
+    /// Prints all settings, a section like `game` or a single setting like `game.memory`
+    Get { key: Option<String> },
+    /// Changes a single setting like `game.memory`, the value is parsed as JSON if possible
+    Set { key: String, value: String },
+}
+
+/// Every error is printed like the gui receives it
+struct CliError(LauncherError);
+
+impl CliError {
+    fn from<E>(error: E) -> CliError
+    where
+        LauncherError: From<E>,
+    {
+        CliError(LauncherError::from(error))
+    }
+
+    fn invalid(message: &'static str) -> CliError {
+        CliError(LauncherError::new("cli.invalid_arguments", message))
+    }
+
+    fn describe(&self) -> String {
+        describe(&self.0)
+    }
+
+    /// The description inside a message like `cli-error`
+    fn localized(&self, key: &str) -> String {
+        moon_launcher_core::locale::translate(key, &[("message", self.describe())])
+    }
+}
+
+/// The message followed by the details of the whole cause chain
+fn describe(error: &LauncherError) -> String {
+    let mut description = error.localized_message();
+    let mut error = Some(error);
+    while let Some(current) = error {
+        if let Some(detail) = current.localized_detail() {
+            description.push_str(&format!("\n  {detail}"));
+        }
+        error = current.cause.as_deref();
+    }
+    description
+}
+
+/// The output of a command, printed as JSON or as text
+struct Output {
+    value: Value,
+    text: String,
+    /// Whether the command should exit with an error even though it ran
+    failed: bool,
+}
+
+impl Output {
+    fn new<T: Serialize>(value: &T, text: String) -> Output {
+        Output {
+            value: serde_json::to_value(value).unwrap_or(Value::Null),
+            text,
+            failed: false,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let paths = match LauncherPaths::resolve() {
+        Ok(paths) => paths,
+        Err(error) => {
+            let error = CliError(LauncherError::from(error));
+            if cli.json {
+                eprintln!("{}", json!({ "error": error.0 }));
+            } else {
+                eprintln!("{}", error.localized("cli-error"));
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+    let settings = SettingsService::load(paths);
+    moon_launcher_core::locale::apply_settings(&settings.get::<InterfaceSettingData>());
+
+    // Printed to stderr, so the output of the command can still be parsed
+    for issue in settings.load_issues() {
+        let warning = CliError(LauncherError::from(issue.clone()));
+        if cli.json {
+            eprintln!("{}", json!({ "warning": warning.0 }));
+        } else {
+            eprintln!("{}", warning.localized("cli-warning"));
+        }
+    }
+
+    match run(cli.command, &settings).await {
+        Ok(output) => {
+            if cli.json {
+                println!("{}", output.value);
+            } else {
+                println!("{}", output.text);
+            }
+            if output.failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(error) => {
+            if cli.json {
+                eprintln!("{}", json!({ "error": error.0 }));
+            } else {
+                eprintln!("{}", error.localized("cli-error"));
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(command: Command, settings: &SettingsService) -> Result<Output, CliError> {
+    match command {
+        Command::Login { uid } => login(settings, uid).await,
+        Command::Channels => channels(settings).await,
+        Command::Versions { channel } => versions(settings, &channel).await,
+        Command::Install { channel, version } => install(settings, &channel, version).await,
+        Command::Launch { channel, offline } => launch(settings, &channel, offline).await,
+        Command::Verify => verify(settings),
+        Command::Settings(SettingsCommand::Get { key }) => get_setting(settings, key),
+        Command::Settings(SettingsCommand::Set { key, value }) => {
+            set_setting(settings, &key, &value)
+        }
+    }
+}
+
+/// Loads the serial and authenticates, the returned session can be used like the one of the gui
+async fn authenticate(
+    settings: &SettingsService,
+    uid: i64,
+) -> Result<(SessionService, AuthenticationResponseData), CliError> {
+    let serial = moon_launcher_core::serial::active_provider(settings.paths())
+        .fetch_serial()
+        .await
+        .map_err(CliError::from)?;
+    let session = SessionService::default();
+    session.set_serial(serial);
+
+    let response = moon_launcher_core::api::moon::auth::authenticate(&session, uid)
+        .await
+        .map_err(CliError::from)?;
+    session.sign_in(
+        response.session_key.clone(),
+        response.available_channels.clone(),
+    );
+
+    // Only used to diagnose HWID mismatches, see the gui login
+    let _ = moon_launcher_core::serial::diagnostics::record_fingerprint(
+        settings.paths(),
+        &session.serial(),
+    );
+    Ok((session, response))
+}
+
+/// Authenticates with the UID of the last login
+async fn authenticate_stored(
+    settings: &SettingsService,
+) -> Result<(SessionService, AuthenticationResponseData), CliError> {
+    let uid = settings.get::<LoginSettingData>().uid;
+    if uid == LoginSettingData::default().uid {
+        return Err(CliError::invalid("cli-not-logged-in"));
+    }
+    authenticate(settings, uid).await
+}
+
+fn find_channel(session: &SessionService, name: &str) -> Result<Channel, CliError> {
+    session
+        .channel(name)
+        .ok_or_else(|| CliError::invalid("cli-no-channel-access"))
+}
+
+async fn login(settings: &SettingsService, uid: i64) -> Result<Output, CliError> {
+    let (_, response) = authenticate(settings, uid).await?;
+    settings
+        .update(|login: &mut LoginSettingData| login.uid = uid)
+        .map_err(CliError::from)?;
+
+    let text = moon_launcher_core::locale::translate(
+        "cli-logged-in",
+        &[
+            ("username", response.username.clone()),
+            ("channels", response.available_channels.len().to_string()),
+        ],
+    );
+    Ok(Output::new(&response, text))
+}
+
+async fn channels(settings: &SettingsService) -> Result<Output, CliError> {
+    let (_, response) = authenticate_stored(settings).await?;
+    let text = response
+        .available_channels
+        .iter()
+        .map(|channel| {
+            format!(
+                "{}\t{}\t{}",
+                channel.name, channel.latest_version, channel.description
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Output::new(&response.available_channels, text))
+}
+
+async fn versions(settings: &SettingsService, channel: &str) -> Result<Output, CliError> {
+    let (session, _) = authenticate_stored(settings).await?;
+    let channel = find_channel(&session, channel)?;
+
+    let text = channel
+        .available_versions
+        .iter()
+        .map(|version| {
+            let mut line = format!("{}\t{}", version.id, version.name);
+            if version.id == channel.latest_version {
+                line.push_str(&moon_launcher_core::locale::translate("cli-latest", &[]));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Output::new(&channel.available_versions, text))
+}
+
+async fn install(
+    settings: &SettingsService,
+    channel: &str,
+    version: Option<String>,
+) -> Result<Output, CliError> {
+    let (session, _) = authenticate_stored(settings).await?;
+    let channel = find_channel(&session, channel)?;
+
+    let selection = match version {
+        Some(version) => VersionSelectionData {
+            channel: channel.name.clone(),
+            preferred_version: version,
+            requires_latest: false,
+        },
+        None => settings
+            .get::<VersionSettingData>()
+            .selection_for(&channel.name),
+    };
+    let resolved = moon_launcher_core::game::version::resolve_version(&channel, &selection)
+        .ok_or_else(|| CliError::invalid("launch-no-version"))?;
+
+    let jar = moon_launcher_core::game::install::install_version(
+        &session,
+        &DownloadService::default(),
+        &ProcessService::default(),
+        &settings.document(),
+        settings.paths(),
+        &channel,
+        &resolved.version,
+    )
+    .await
+    .map_err(CliError::from)?;
+
+    let text = moon_launcher_core::locale::translate(
+        "cli-installed",
+        &[
+            ("channel", channel.name.clone()),
+            ("version", resolved.version.clone()),
+            ("path", jar.display().to_string()),
+        ],
+    );
+    let text = with_warning(&resolved, text);
+    Ok(Output::new(
+        &json!({ "version": resolved, "path": jar }),
+        text,
+    ))
+}
+
+async fn launch(
+    settings: &SettingsService,
+    channel: &str,
+    offline: bool,
+) -> Result<Output, CliError> {
+    let session = if offline {
+        SessionService::default()
+    } else {
+        authenticate_stored(settings).await?.0
+    };
+    let document = settings.document();
+    let selection = document.versions.selection_for(channel);
+    let paths = settings.paths();
+    let options = LaunchOptions::from_settings(paths, &document.game, &document.wine);
+
+    let resolved = launch_selection(
+        &session,
+        &DownloadService::default(),
+        &ProcessService::default(),
+        &document,
+        paths,
+        &selection,
+        options,
+    )
+    .await
+    .map_err(CliError::from)?;
+
+    let text = moon_launcher_core::locale::translate(
+        "cli-launched",
+        &[
+            ("channel", channel.to_string()),
+            ("version", resolved.version.clone()),
+        ],
+    );
+    Ok(Output::new(&resolved, with_warning(&resolved, text)))
+}
+
+/// Puts the warning of a resolved version in front of the text, if there is one
+fn with_warning(resolved: &ResolvedVersion, text: String) -> String {
+    match &resolved.warning {
+        Some(warning) => {
+            let warning = moon_launcher_core::locale::translate(
+                "cli-warning",
+                &[("message", warning.clone())],
+            );
+            format!("{warning}\n{text}")
+        }
+        None => text,
+    }
+}
+
+/// The result of checking a single file
+#[derive(Serialize)]
+struct FileCheck {
+    file: String,
+    /// Empty if the file is fine
+    errors: Vec<LauncherError>,
+}
+
+fn verify(settings: &SettingsService) -> Result<Output, CliError> {
+    let paths = settings.paths();
+
+    // Every file is validated as it is stored, the settings in memory might hide broken files
+    let mut checks: Vec<FileCheck> = moon_launcher_core::settings::verify_storage_files(paths)
+        .into_iter()
+        .map(|verification| FileCheck {
+            file: moon_launcher_core::storage::storage_path(paths, verification.storage_type)
+                .display()
+                .to_string(),
+            errors: verification
+                .errors
+                .into_iter()
+                .map(LauncherError::from)
+                .collect(),
+        })
+        .collect();
+
+    // A broken installations file has already been reported above
+    let installations = moon_launcher_core::game::install::load_installations(paths)
+        .map(|installations| installations.installations)
+        .unwrap_or_default();
+    for installation in installations {
+        let jar = moon_launcher_core::game::install::version_jar(
+            paths,
+            &installation.channel,
+            &installation.version,
+        );
+        checks.push(FileCheck {
+            errors: (!jar.is_file())
+                .then(|| LauncherError::new("cli.version_missing", "version-missing"))
+                .into_iter()
+                .collect(),
+            file: jar.display().to_string(),
+        });
+    }
+
+    let failed = checks.iter().any(|check| !check.errors.is_empty());
+    let text = checks
+        .iter()
+        .map(|check| {
+            let status = if check.errors.is_empty() {
+                "cli-file-ok"
+            } else {
+                "cli-file-broken"
+            };
+            let mut line =
+                moon_launcher_core::locale::translate(status, &[("file", check.file.clone())]);
+            for error in &check.errors {
+                let description = describe(error).replace('\n', "\n  ");
+                line.push_str(&format!("\n  {description}"));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Output {
+        failed,
+        ..Output::new(&checks, text)
+    })
+}
+
+fn get_setting(settings: &SettingsService, key: Option<String>) -> Result<Output, CliError> {
+    let document = serde_json::to_value(settings.document())
+        .map_err(|_| CliError::invalid("cli-settings-serialize"))?;
+
+    let mut value = &document;
+    for part in key.iter().flat_map(|key| key.split('.')) {
+        value = value
+            .get(part)
+            .ok_or_else(|| CliError::invalid("cli-unknown-setting"))?;
+    }
+
+    let text = serde_json::to_string_pretty(value).unwrap_or_default();
+    Ok(Output::new(value, text))
+}
+
+fn set_setting(settings: &SettingsService, key: &str, value: &str) -> Result<Output, CliError> {
+    let (section, field) = key
+        .split_once('.')
+        .ok_or_else(|| CliError::invalid("cli-setting-name"))?;
+
+    let document = serde_json::to_value(settings.document())
+        .map_err(|_| CliError::invalid("cli-settings-serialize"))?;
+    let mut section_value = document
+        .get(section)
+        .cloned()
+        .ok_or_else(|| CliError::invalid("cli-unknown-section"))?;
+    let setting = section_value
+        .get_mut(field)
+        .ok_or_else(|| CliError::invalid("cli-unknown-setting"))?;
+
+    // Plain words like a directory are taken as they are, everything else is parsed as JSON
+    *setting = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+
+    let patch: SettingsPatch = serde_json::from_value(json!({ section: section_value }))
+        .map_err(|_| CliError::invalid("cli-invalid-value"))?;
+    let document = settings.patch(patch).map_err(CliError::from)?;
+
+    let changed = serde_json::to_value(&document)
+        .ok()
+        .and_then(|document| document.get(section)?.get(field).cloned())
+        .unwrap_or(Value::Null);
+    Ok(Output::new(&changed, format!("{key} = {changed}")))
+}
@@ -1,10 +1,10 @@
 use crate::api::endpoint::{Endpoint, EndpointType};
 use crate::api::moon::auth::AuthenticationError::{
     HwidMismatch, InternalServerError, InvalidLoginRequest, InvalidUserAccount, JsonParseFailed,
-    NoUserFound, RequestFailed, Unknown,
+    NoUserFound, RequestFailed, SerialUnavailable, Unknown,
 };
 use crate::api::moon::BASE_URL;
-use crate::gui::LauncherState;
+use crate::session::SessionStore;
 use reqwest::header::HeaderMap;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
@@ -19,9 +19,10 @@ use serde::{Deserialize, Serialize};
 /// ## Error Code Definition
 /// - 0: Invalid login request received, please open a GitHub issue
 /// - 1: Please make sure you set both a HWID and a username through the discord bot
-/// - 2: Your HWID does not match, please create a HWID reset
+/// - 2: Your HWID does not match, please check what changed and request a HWID reset
 /// - 3: No user with the UID you entered could be found, please make sure you entered your UID correctly
 /// - 4: Internal server error, please create a ticket
+///
 /// <br><br>
 ///
 /// ## Valid Outcome Definition
@@ -79,6 +80,10 @@ pub struct Channel {
     pub latest_version: String,
     pub last_updated: i64,
     pub available_versions: Vec<Version>,
+    /// The memory (in MiB) the channel needs at least, only sent for channels which need more
+    /// than usual
+    #[serde(default)]
+    pub minimum_memory: Option<i64>,
 }
 
 /// The version struct containing all information about an available channel version
@@ -134,16 +139,41 @@ pub enum AuthenticationError {
     HwidMismatch { message: &'static str },
     NoUserFound { message: &'static str },
     InternalServerError { message: &'static str },
+    SerialUnavailable { message: &'static str },
     Unknown,
 }
 
+impl AuthenticationError {
+    /// Returns the key of the message describing the error, which is translated by [crate::locale]
+    pub fn message(&self) -> &'static str {
+        match self {
+            RequestFailed => "server-unreachable",
+            JsonParseFailed => "auth-invalid-response",
+            InvalidLoginRequest { message }
+            | InvalidUserAccount { message }
+            | HwidMismatch { message }
+            | NoUserFound { message }
+            | InternalServerError { message }
+            | SerialUnavailable { message } => message,
+            Unknown => "unknown-error",
+        }
+    }
+}
+
 /// Authenticates with the backend servers
 pub async fn authenticate(
-    state: &LauncherState,
+    session: &dyn SessionStore,
     uid: i64,
 ) -> Result<AuthenticationResponseData, AuthenticationError> {
+    // Without a serial the servers would only answer with a HWID mismatch
+    if session.serial().is_empty() {
+        return Err(SerialUnavailable {
+            message: "auth-serial-unavailable",
+        });
+    }
+
     let endpoint = AuthenticationEndpointData { uid };
-    let response = crate::api::requester::create_request(state, endpoint)
+    let response = crate::api::requester::create_request(session, endpoint)
         .await
         .map_err(|_| RequestFailed)?;
 
@@ -156,12 +186,22 @@ pub async fn authenticate(
     // Handle error mappings if status code is not ok
     if status != StatusCode::OK {
         return Err(match content.as_str() {
-            "0" => InvalidLoginRequest { message: "Invalid login request received, please open a GitHub issue" },
-            "1" => InvalidUserAccount { message: "Please make sure you set both a HWID and a username through the discord bot" },
-            "2" => HwidMismatch { message: "Your HWID does not match, please create a HWID reset" },
-            "3" => NoUserFound { message: "No user with the UID you entered could be found, please make sure you entered your UID correctly" },
-            "4" => InternalServerError { message: "Internal server error, please create a ticket" },
-            _ => Unknown
+            "0" => InvalidLoginRequest {
+                message: "auth-invalid-request",
+            },
+            "1" => InvalidUserAccount {
+                message: "auth-invalid-account",
+            },
+            "2" => HwidMismatch {
+                message: "auth-hwid-mismatch",
+            },
+            "3" => NoUserFound {
+                message: "auth-no-user",
+            },
+            "4" => InternalServerError {
+                message: "server-internal-error",
+            },
+            _ => Unknown,
         });
     }
 
@@ -170,3 +210,16 @@ pub async fn authenticate(
         _ => Err(JsonParseFailed),
     }
 }
+
+/// Authenticates again and replaces the session, which invalidates the previous one
+///
+/// The channels are only part of the authentication response, so this is also how they are
+/// refreshed. Requests made with the previous session renew it once they have been rejected
+pub async fn renew_session(
+    session: &dyn SessionStore,
+    uid: i64,
+) -> Result<Vec<Channel>, AuthenticationError> {
+    let response = authenticate(session, uid).await?;
+    session.sign_in(response.session_key, response.available_channels.clone());
+    Ok(response.available_channels)
+}
@@ -4,10 +4,10 @@ use crate::api::moon::download::DownloadRequestError::{
     JsonParseError, RateLimited, RequestFailed, UnknownError,
 };
 use crate::api::moon::BASE_URL;
-use crate::gui::LauncherState;
+use crate::session::SessionStore;
 use reqwest::header::HeaderMap;
 use reqwest::StatusCode;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Endpoint for requesting a specific version from the backend server
 /// Every user can only request a download every 60 seconds to reduce traffic and general
@@ -22,6 +22,7 @@ use serde::Deserialize;
 /// - 2: Internal server error, please create a ticket
 /// - 3: Please make sure you set both a HWID and a username through the discord bot
 /// - 4: You don't have enough permissions to download this channel
+///
 /// <br><br>
 ///
 /// ## Valid Outcome Definition
@@ -40,10 +41,9 @@ pub struct DownloadRequestEndpointData {
 }
 
 /// Contains the response data as described in the [DownloadRequestEndpointData] documentation
-#[allow(dead_code)]
 #[derive(Deserialize)]
 pub struct DownloadResponseData {
-    download_link: String,
+    pub download_link: String,
 }
 
 impl Endpoint for DownloadRequestEndpointData {
@@ -66,9 +66,29 @@ impl Endpoint for DownloadRequestEndpointData {
     }
 }
 
+/// Endpoint for downloading the actual build file of a version
+/// The link is received through the [DownloadRequestEndpointData] and can only be used once
+pub struct DownloadFileEndpointData {
+    pub download_link: String,
+}
+
+impl Endpoint for DownloadFileEndpointData {
+    fn url(&self) -> String {
+        self.download_link.clone()
+    }
+
+    /// The download link already contains the download code, no authentication is required
+    fn request_type(&self) -> EndpointType {
+        EndpointType::Normal
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        None
+    }
+}
+
 /// All different errors with their mappings which can occur upon download requesting
-#[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum DownloadRequestError {
     RequestFailed,
     JsonParseError,
@@ -80,20 +100,35 @@ pub enum DownloadRequestError {
     UnknownError,
 }
 
-/// Authenticates with the backend servers
-#[allow(dead_code)]
+impl DownloadRequestError {
+    /// Returns the key of the message describing the error, which is translated by [crate::locale]
+    pub fn message(&self) -> &'static str {
+        match self {
+            RequestFailed => "download-unreachable",
+            JsonParseError => "download-invalid-response",
+            InvalidSession { message }
+            | RateLimited { message }
+            | InternalServerError { message }
+            | InvalidUserAccount { message }
+            | InsufficientPermissions { message } => message,
+            UnknownError => "unknown-error",
+        }
+    }
+}
+
+/// Requests a download link for a channel version from the backend servers
 pub async fn request_download(
-    state: &LauncherState,
+    session: &dyn SessionStore,
     channel_name: String,
     channel_version: String,
 ) -> Result<DownloadResponseData, DownloadRequestError> {
     let endpoint = DownloadRequestEndpointData {
-        session_token: state.session_token.clone(),
+        session_token: session.session_token(),
         channel_name,
         channel_version,
     };
 
-    let response = crate::api::requester::create_request(state, endpoint)
+    let response = crate::api::requester::create_request(session, endpoint)
         .await
         .map_err(|_| RequestFailed)?;
 
@@ -107,20 +142,19 @@ pub async fn request_download(
     if status != StatusCode::OK {
         return Err(match content.as_str() {
             "0" => InvalidSession {
-                message: "Your session expired, please restart the launcher",
+                message: "download-invalid-session",
             },
             "1" => RateLimited {
-                message: "You are currently rate-limited, please wait one minute",
+                message: "server-rate-limited",
             },
             "2" => InternalServerError {
-                message: "Internal server error, please create a ticket",
+                message: "server-internal-error",
             },
             "3" => InvalidUserAccount {
-                message:
-                    "Please make sure you set both a HWID and a username through the discord bot",
+                message: "auth-invalid-account",
             },
             "4" => InsufficientPermissions {
-                message: "You don't have enough permissions to download this ",
+                message: "download-insufficient-permissions",
             },
             _ => UnknownError,
         });
@@ -0,0 +1,192 @@
+use crate::api::endpoint::{Endpoint, EndpointType};
+use crate::api::moon::hwid::HwidResetError::{
+    AlreadyRequested, InternalServerError, InvalidRequest, JsonParseError, NoRequestFound,
+    NoUserFound, RateLimited, RequestFailed, SerialUnavailable, UnknownError,
+};
+use crate::api::moon::BASE_URL;
+use crate::session::SessionStore;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Endpoint for filing a HWID reset request, the serial of the current device becomes the new
+/// HWID of the account once a staff member approves it<br><br>
+///
+/// # Output Definition
+/// This endpoint can both produce a valid JSON outcome and a few error codes<br><br>
+///
+/// ## Error Code Definition
+/// - 0: Invalid HWID reset request received, please open a GitHub issue
+/// - 1: No user with the UID you entered could be found
+/// - 2: You already requested a HWID reset, please wait until it has been reviewed
+/// - 3: You are currently rate-limited, please wait one minute
+/// - 4: Internal server error, please create a ticket
+///
+/// <br><br>
+///
+/// ## Valid Outcome Definition
+/// The valid outcome, is as previously mentioned a valid JSON string which looks
+/// similar to the following
+///
+/// ```json
+/// {
+///     "status": "PENDING",
+///     "requestedAt": 0,
+///     "reviewedAt": null
+/// }
+/// ```
+pub struct HwidResetRequestEndpointData {
+    pub uid: i64,
+}
+
+/// Endpoint for fetching the status of the latest HWID reset request of an account, the
+/// output is defined like the one of [HwidResetRequestEndpointData] besides the error codes<br><br>
+///
+/// ## Error Code Definition
+/// - 0: Invalid HWID reset request received, please open a GitHub issue
+/// - 1: No user with the UID you entered could be found
+/// - 4: Internal server error, please create a ticket
+/// - 5: You haven't requested a HWID reset yet
+pub struct HwidResetStatusEndpointData {
+    pub uid: i64,
+}
+
+/// Contains the response data as described in the [HwidResetRequestEndpointData] documentation
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HwidResetResponseData {
+    pub status: HwidResetStatus,
+    pub requested_at: i64,
+    pub reviewed_at: Option<i64>,
+}
+
+/// The states a HWID reset request goes through
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HwidResetStatus {
+    Pending,
+    Approved,
+    Denied,
+}
+
+impl Endpoint for HwidResetRequestEndpointData {
+    fn url(&self) -> String {
+        format!("{BASE_URL}hwid/reset/request?uid={}", self.uid)
+    }
+
+    /// The serial of this device is the one the HWID is reset to
+    fn request_type(&self) -> EndpointType {
+        EndpointType::Serial
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        None
+    }
+}
+
+impl Endpoint for HwidResetStatusEndpointData {
+    fn url(&self) -> String {
+        format!("{BASE_URL}hwid/reset/status?uid={}", self.uid)
+    }
+
+    /// Only the device which filed the request may see its status
+    fn request_type(&self) -> EndpointType {
+        EndpointType::Serial
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        None
+    }
+}
+
+/// All different errors with their mappings which can occur upon requesting a HWID reset or
+/// fetching its status
+#[derive(Debug, Serialize, Deserialize)]
+pub enum HwidResetError {
+    RequestFailed { message: &'static str },
+    JsonParseError { message: &'static str },
+    InvalidRequest { message: &'static str },
+    NoUserFound { message: &'static str },
+    AlreadyRequested { message: &'static str },
+    RateLimited { message: &'static str },
+    InternalServerError { message: &'static str },
+    NoRequestFound { message: &'static str },
+    SerialUnavailable { message: &'static str },
+    UnknownError { message: &'static str },
+}
+
+/// Files a HWID reset request for the account with the serial of this device
+pub async fn request_hwid_reset(
+    session: &dyn SessionStore,
+    uid: i64,
+) -> Result<HwidResetResponseData, HwidResetError> {
+    send(session, HwidResetRequestEndpointData { uid }).await
+}
+
+/// Fetches the status of the latest HWID reset request of the account
+pub async fn fetch_hwid_reset_status(
+    session: &dyn SessionStore,
+    uid: i64,
+) -> Result<HwidResetResponseData, HwidResetError> {
+    send(session, HwidResetStatusEndpointData { uid }).await
+}
+
+/// Both endpoints share their output and error codes
+async fn send(
+    session: &dyn SessionStore,
+    endpoint: impl Endpoint,
+) -> Result<HwidResetResponseData, HwidResetError> {
+    // Without a serial the servers could never match the request to this device
+    if session.serial().is_empty() {
+        return Err(SerialUnavailable {
+            message: "auth-serial-unavailable",
+        });
+    }
+
+    let response = crate::api::requester::create_request(session, endpoint)
+        .await
+        .map_err(|_| RequestFailed {
+            message: "server-unreachable",
+        })?;
+
+    let status = response.status();
+    let content = response
+        .text_with_charset("UTF-8")
+        .await
+        .map_err(|_| RequestFailed {
+            message: "server-unreachable",
+        })?;
+
+    // Handle error mappings if status code is not ok
+    if status != StatusCode::OK {
+        return Err(match content.as_str() {
+            "0" => InvalidRequest {
+                message: "hwid-invalid-request",
+            },
+            "1" => NoUserFound {
+                message: "auth-no-user",
+            },
+            "2" => AlreadyRequested {
+                message: "hwid-already-requested",
+            },
+            "3" => RateLimited {
+                message: "server-rate-limited",
+            },
+            "4" => InternalServerError {
+                message: "server-internal-error",
+            },
+            "5" => NoRequestFound {
+                message: "hwid-no-request",
+            },
+            _ => UnknownError {
+                message: "unknown-error",
+            },
+        });
+    }
+
+    serde_json::from_slice::<HwidResetResponseData>(content.as_bytes()).map_err(|_| {
+        JsonParseError {
+            message: "hwid-invalid-response",
+        }
+    })
+}
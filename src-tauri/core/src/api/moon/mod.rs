@@ -1,4 +1,5 @@
 pub mod auth;
 pub mod download;
+pub mod hwid;
 
 pub const BASE_URL: &str = "https://backend.moonclient.xyz/api/v1/launcher/";
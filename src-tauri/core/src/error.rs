@@ -0,0 +1,463 @@
+use std::collections::BTreeMap;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::api::moon::auth::AuthenticationError;
+use crate::api::moon::download::DownloadRequestError;
+use crate::api::moon::hwid::HwidResetError;
+use crate::game::install::InstallError;
+use crate::game::launch::LaunchError;
+use crate::game::profile::ProfileError;
+use crate::game::retention::RollbackError;
+use crate::game::wine::WineError;
+use crate::proprietary::ProprietaryError;
+use crate::serial::SerialError;
+use crate::settings::{LoadIssue, SettingsError};
+use crate::storage::location::StorageLocationError;
+use crate::storage::{NamedStorage, StorageError};
+
+/// The error returned by every command, so all errors can be rendered the same way
+///
+/// The errors of the single modules stay the source of truth and are converted into this one
+/// once they leave the core. Messages are only translated once the error is serialized, so it
+/// is always shown in the locale which is active at that moment
+#[derive(Debug)]
+pub struct LauncherError {
+    /// A machine-readable code like `auth.hwid_mismatch`, codes are never changed once released
+    pub code: &'static str,
+    /// The key of the message which can be displayed to the user, see [crate::locale]
+    pub message: &'static str,
+    /// The arguments used by the message and the detail
+    pub arguments: Vec<(&'static str, String)>,
+    /// Additional information like the file an error occurred on
+    pub detail: Option<Detail>,
+    /// The error which caused this one
+    pub cause: Option<Box<LauncherError>>,
+    /// Whether trying the same thing again might succeed
+    pub retryable: bool,
+}
+
+/// Additional information about a [LauncherError]
+#[derive(Debug)]
+pub enum Detail {
+    /// Shown as it is, like the path of a file
+    Text(String),
+    /// The key of a message which is translated with the arguments of the error
+    Message(&'static str),
+}
+
+impl LauncherError {
+    pub fn new(code: &'static str, message: &'static str) -> LauncherError {
+        LauncherError {
+            code,
+            message,
+            arguments: Vec::new(),
+            detail: None,
+            cause: None,
+            retryable: false,
+        }
+    }
+
+    /// Creates an error which might not occur again if the user retries
+    pub fn retryable(code: &'static str, message: &'static str) -> LauncherError {
+        LauncherError {
+            retryable: true,
+            ..LauncherError::new(code, message)
+        }
+    }
+
+    pub fn with_argument(mut self, name: &'static str, value: impl ToString) -> LauncherError {
+        self.arguments.push((name, value.to_string()));
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> LauncherError {
+        self.detail = Some(Detail::Text(detail.into()));
+        self
+    }
+
+    /// Uses a translated message as the detail, which gets the arguments of this error
+    pub fn with_detail_message(mut self, key: &'static str) -> LauncherError {
+        self.detail = Some(Detail::Message(key));
+        self
+    }
+
+    /// Attaches the error which caused this one, retrying is possible if it is for the cause
+    pub fn caused_by(mut self, cause: impl Into<LauncherError>) -> LauncherError {
+        let cause = cause.into();
+        self.retryable = cause.retryable;
+        self.cause = Some(Box::new(cause));
+        self
+    }
+
+    /// The message translated into the active locale
+    pub fn localized_message(&self) -> String {
+        crate::locale::translate(self.message, &self.arguments)
+    }
+
+    /// The detail translated into the active locale, if it is a message
+    pub fn localized_detail(&self) -> Option<String> {
+        match &self.detail {
+            Some(Detail::Text(text)) => Some(text.clone()),
+            Some(Detail::Message(key)) => Some(crate::locale::translate(key, &self.arguments)),
+            None => None,
+        }
+    }
+}
+
+/// Serialized with the translated message and detail, the gui renders them as they are and only
+/// looks at the code and the key to react to single errors
+impl Serialize for LauncherError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let arguments: BTreeMap<_, _> = self.arguments.iter().cloned().collect();
+        let mut error = serializer.serialize_struct("LauncherError", 7)?;
+        error.serialize_field("code", self.code)?;
+        error.serialize_field("key", self.message)?;
+        error.serialize_field("arguments", &arguments)?;
+        error.serialize_field("message", &self.localized_message())?;
+        error.serialize_field("detail", &self.localized_detail())?;
+        error.serialize_field("cause", &self.cause)?;
+        error.serialize_field("retryable", &self.retryable)?;
+        error.end()
+    }
+}
+
+impl From<AuthenticationError> for LauncherError {
+    fn from(error: AuthenticationError) -> Self {
+        let message = error.message();
+        match error {
+            AuthenticationError::RequestFailed => {
+                LauncherError::retryable("auth.request_failed", message)
+            }
+            AuthenticationError::JsonParseFailed => {
+                LauncherError::new("auth.invalid_response", message)
+            }
+            AuthenticationError::InvalidLoginRequest { .. } => {
+                LauncherError::new("auth.invalid_request", message)
+            }
+            AuthenticationError::InvalidUserAccount { .. } => {
+                LauncherError::new("auth.invalid_account", message)
+            }
+            AuthenticationError::HwidMismatch { .. } => {
+                LauncherError::new("auth.hwid_mismatch", message)
+            }
+            AuthenticationError::NoUserFound { .. } => LauncherError::new("auth.no_user", message),
+            AuthenticationError::InternalServerError { .. } => {
+                LauncherError::retryable("auth.server_error", message)
+            }
+            AuthenticationError::SerialUnavailable { .. } => {
+                LauncherError::new("auth.serial_unavailable", message)
+            }
+            AuthenticationError::Unknown => LauncherError::new("auth.unknown", message),
+        }
+    }
+}
+
+impl From<DownloadRequestError> for LauncherError {
+    fn from(error: DownloadRequestError) -> Self {
+        let message = error.message();
+        match error {
+            DownloadRequestError::RequestFailed => {
+                LauncherError::retryable("download.request_failed", message)
+            }
+            DownloadRequestError::JsonParseError => {
+                LauncherError::new("download.invalid_response", message)
+            }
+            DownloadRequestError::InvalidSession { .. } => {
+                LauncherError::new("download.invalid_session", message)
+            }
+            DownloadRequestError::RateLimited { .. } => {
+                LauncherError::retryable("download.rate_limited", message)
+            }
+            DownloadRequestError::InternalServerError { .. } => {
+                LauncherError::retryable("download.server_error", message)
+            }
+            DownloadRequestError::InvalidUserAccount { .. } => {
+                LauncherError::new("download.invalid_account", message)
+            }
+            DownloadRequestError::InsufficientPermissions { .. } => {
+                LauncherError::new("download.insufficient_permissions", message)
+            }
+            DownloadRequestError::UnknownError => LauncherError::new("download.unknown", message),
+        }
+    }
+}
+
+impl From<HwidResetError> for LauncherError {
+    fn from(error: HwidResetError) -> Self {
+        match error {
+            HwidResetError::RequestFailed { message } => {
+                LauncherError::retryable("hwid.request_failed", message)
+            }
+            HwidResetError::JsonParseError { message } => {
+                LauncherError::new("hwid.invalid_response", message)
+            }
+            HwidResetError::InvalidRequest { message } => {
+                LauncherError::new("hwid.invalid_request", message)
+            }
+            HwidResetError::NoUserFound { message } => LauncherError::new("hwid.no_user", message),
+            HwidResetError::AlreadyRequested { message } => {
+                LauncherError::new("hwid.already_requested", message)
+            }
+            HwidResetError::RateLimited { message } => {
+                LauncherError::retryable("hwid.rate_limited", message)
+            }
+            HwidResetError::InternalServerError { message } => {
+                LauncherError::retryable("hwid.server_error", message)
+            }
+            HwidResetError::NoRequestFound { message } => {
+                LauncherError::new("hwid.no_request", message)
+            }
+            HwidResetError::SerialUnavailable { message } => {
+                LauncherError::new("hwid.serial_unavailable", message)
+            }
+            HwidResetError::UnknownError { message } => LauncherError::new("hwid.unknown", message),
+        }
+    }
+}
+
+/// Everything which fails because of the downloaded library is retryable, as retrying
+/// downloads the library again
+impl From<ProprietaryError> for LauncherError {
+    fn from(error: ProprietaryError) -> Self {
+        match error {
+            ProprietaryError::Location { message } => {
+                LauncherError::new("proprietary.location", message)
+            }
+            ProprietaryError::DownloadFailed { message } => {
+                LauncherError::retryable("proprietary.download_failed", message)
+            }
+            ProprietaryError::UnexpectedStatus { message, status } => {
+                LauncherError::retryable("proprietary.unexpected_status", message)
+                    .with_argument("status", status)
+                    .with_detail_message("detail-http-status")
+            }
+            ProprietaryError::FileWrite { message, path } => {
+                LauncherError::retryable("proprietary.file_write", message)
+                    .with_detail(path.display().to_string())
+            }
+            ProprietaryError::LoadFailed { message, path } => {
+                LauncherError::retryable("proprietary.load_failed", message)
+                    .with_detail(path.display().to_string())
+            }
+            ProprietaryError::MissingSymbol { message } => {
+                LauncherError::retryable("proprietary.missing_symbol", message)
+            }
+            ProprietaryError::SerialUnavailable { message, code } => {
+                LauncherError::new("proprietary.serial_unavailable", message)
+                    .with_argument("code", code)
+                    .with_detail_message("detail-error-code")
+            }
+            ProprietaryError::AbiMismatch {
+                message,
+                expected,
+                found,
+            } => LauncherError::retryable("proprietary.abi_mismatch", message)
+                .with_argument("expected", expected)
+                .with_argument("found", found)
+                .with_detail_message("detail-abi-version"),
+            ProprietaryError::InvalidManifest { message } => {
+                LauncherError::retryable("proprietary.invalid_manifest", message)
+            }
+            ProprietaryError::IntegrityCheckFailed { message } => {
+                LauncherError::retryable("proprietary.integrity_check_failed", message)
+            }
+        }
+    }
+}
+
+impl From<SerialError> for LauncherError {
+    fn from(error: SerialError) -> Self {
+        match error {
+            SerialError::Proprietary { message, cause } => {
+                LauncherError::new("serial.proprietary", message).caused_by(cause)
+            }
+            SerialError::Unavailable { message } => {
+                LauncherError::new("serial.unavailable", message)
+            }
+            SerialError::Unsupported { message } => {
+                LauncherError::new("serial.unsupported", message)
+            }
+        }
+    }
+}
+
+impl From<SettingsError> for LauncherError {
+    fn from(error: SettingsError) -> Self {
+        match error {
+            SettingsError::Invalid { message, field } => {
+                LauncherError::new("settings.invalid", message)
+                    .with_argument("field", field)
+                    .with_detail_message("detail-invalid-setting")
+            }
+            SettingsError::Storage { message, cause } => {
+                LauncherError::new("settings.storage", message).caused_by(cause)
+            }
+        }
+    }
+}
+
+impl From<LoadIssue> for LauncherError {
+    fn from(issue: LoadIssue) -> Self {
+        match issue {
+            LoadIssue::Restored { storage_type } => {
+                LauncherError::new("settings.restored", "settings-restored-backup")
+                    .with_argument("file", format!("{}.json", storage_type.file_name()))
+            }
+            LoadIssue::Failed {
+                storage_type,
+                cause,
+            } => LauncherError::new("settings.load_failed", "settings-load-failed")
+                .with_argument("file", format!("{}.json", storage_type.file_name()))
+                .caused_by(cause),
+        }
+    }
+}
+
+impl From<StorageError> for LauncherError {
+    fn from(error: StorageError) -> Self {
+        match error {
+            // The file might only be locked by another program for a moment
+            StorageError::Io {
+                message,
+                path,
+                kind,
+            } => LauncherError::retryable("storage.io", message)
+                .with_detail(format!("{}: {kind}", path.display())),
+            StorageError::Parse { message, path } => {
+                LauncherError::new("storage.parse", message).with_detail(path.display().to_string())
+            }
+            StorageError::JsonSerialize { message } => {
+                LauncherError::new("storage.serialize", message)
+            }
+            StorageError::Location { message, cause } => {
+                LauncherError::new("storage.location", message).caused_by(cause)
+            }
+            StorageError::Shared { message } => LauncherError::new("storage.shared", message),
+        }
+    }
+}
+
+impl From<StorageLocationError> for LauncherError {
+    fn from(error: StorageLocationError) -> Self {
+        match error {
+            StorageLocationError::BaseDirectoriesMissing { message } => {
+                LauncherError::new("location.base_directories_missing", message)
+            }
+            StorageLocationError::UnableToCreateWorkingDirectory { message, path } => {
+                LauncherError::new("location.working_directory", message)
+                    .with_detail(path.display().to_string())
+            }
+            StorageLocationError::UnableToCreateMinecraftDirectory { message, path } => {
+                LauncherError::new("location.minecraft_directory", message)
+                    .with_detail(path.display().to_string())
+            }
+        }
+    }
+}
+
+impl From<InstallError> for LauncherError {
+    fn from(error: InstallError) -> Self {
+        match error {
+            InstallError::DownloadRequest { message, cause } => {
+                LauncherError::new("install.download_request", message).caused_by(cause)
+            }
+            InstallError::Authentication { cause, .. } => LauncherError::from(cause),
+            InstallError::DownloadFailed { message } => {
+                LauncherError::retryable("install.download_failed", message)
+            }
+            InstallError::FileWrite { message } => {
+                LauncherError::new("install.file_write", message)
+            }
+            InstallError::Location { message } => LauncherError::new("install.location", message),
+            InstallError::InsufficientSpace {
+                message,
+                required,
+                available,
+            } => LauncherError::new("install.insufficient_space", message)
+                .with_argument("required", required)
+                .with_argument("available", available)
+                .with_detail_message("detail-disk-space"),
+            InstallError::Storage { message, cause } => {
+                LauncherError::new("install.storage", message).caused_by(cause)
+            }
+        }
+    }
+}
+
+impl From<RollbackError> for LauncherError {
+    fn from(error: RollbackError) -> Self {
+        match error {
+            RollbackError::NoPreviousVersion { message } => {
+                LauncherError::new("rollback.no_previous_version", message)
+            }
+            RollbackError::SelectionSave { message, cause } => {
+                LauncherError::new("rollback.selection_save", message).caused_by(cause)
+            }
+        }
+    }
+}
+
+impl From<LaunchError> for LauncherError {
+    fn from(error: LaunchError) -> Self {
+        match error {
+            LaunchError::UnknownChannel { message } => {
+                LauncherError::new("launch.unknown_channel", message)
+            }
+            LaunchError::UnknownProfile { message } => {
+                LauncherError::new("launch.unknown_profile", message)
+            }
+            LaunchError::NoVersionAvailable { message } => {
+                LauncherError::new("launch.no_version", message)
+            }
+            LaunchError::InstallFailed { message, cause } => {
+                LauncherError::new("launch.install_failed", message).caused_by(cause)
+            }
+            LaunchError::Location { message } => LauncherError::new("launch.location", message),
+            LaunchError::Hook { message } => LauncherError::new("launch.hook", message),
+            LaunchError::ProcessSpawn { message } => {
+                LauncherError::new("launch.process_spawn", message)
+            }
+            LaunchError::InsufficientMemory {
+                message,
+                memory,
+                minimum,
+            } => LauncherError::new("launch.insufficient_memory", message)
+                .with_argument("memory", memory)
+                .with_argument("minimum", minimum),
+        }
+    }
+}
+
+impl From<ProfileError> for LauncherError {
+    fn from(error: ProfileError) -> Self {
+        match error {
+            ProfileError::UnknownProfile { message } => {
+                LauncherError::new("profile.unknown", message)
+            }
+            ProfileError::FileWrite { message } => {
+                LauncherError::new("profile.file_write", message)
+            }
+            ProfileError::Settings { message, cause } => {
+                LauncherError::new("profile.settings", message).caused_by(cause)
+            }
+        }
+    }
+}
+
+impl From<WineError> for LauncherError {
+    fn from(error: WineError) -> Self {
+        match error {
+            WineError::Disabled { message } => LauncherError::new("wine.disabled", message),
+            WineError::Unsupported { message } => LauncherError::new("wine.unsupported", message),
+            WineError::Location { message } => LauncherError::new("wine.location", message),
+            WineError::PrefixCreation { message } => {
+                LauncherError::new("wine.prefix_creation", message)
+            }
+            WineError::ProcessSpawn { message } => {
+                LauncherError::new("wine.process_spawn", message)
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Held while a version is being installed, see [DownloadTracker::version_lock]
+pub type VersionLock = Arc<tokio::sync::Mutex<()>>;
+
+/// Coordinates the downloads of builds
+pub trait DownloadTracker: Send + Sync {
+    /// Returns the lock of a channel version, installations of the same version hold it so
+    /// they never write the same files at once
+    fn version_lock(&self, channel: &str, version: &str) -> VersionLock;
+
+    /// Whether the user has already been notified about exactly this update of a channel
+    fn is_notified(&self, channel: &str, last_updated: i64) -> bool;
+
+    /// Remembers that the user has been notified about an update of a channel, returns false
+    /// if they already have been notified about exactly this update
+    fn mark_notified(&self, channel: &str, last_updated: i64) -> bool;
+}
+
+/// Tracks the downloads for as long as the launcher runs
+#[derive(Default)]
+pub struct DownloadService {
+    version_locks: Mutex<HashMap<(String, String), VersionLock>>,
+    /// Maps channel names to the `last_updated` timestamp the user has last been notified about
+    notified_updates: Mutex<HashMap<String, i64>>,
+}
+
+impl DownloadTracker for DownloadService {
+    fn version_lock(&self, channel: &str, version: &str) -> VersionLock {
+        self.version_locks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((channel.to_string(), version.to_string()))
+            .or_default()
+            .clone()
+    }
+
+    fn is_notified(&self, channel: &str, last_updated: i64) -> bool {
+        self.notified_updates
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(channel)
+            == Some(&last_updated)
+    }
+
+    fn mark_notified(&self, channel: &str, last_updated: i64) -> bool {
+        let mut notified_updates = self
+            .notified_updates
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        notified_updates.insert(channel.to_string(), last_updated) != Some(last_updated)
+    }
+}
@@ -0,0 +1,290 @@
+use std::fs::create_dir_all;
+use std::path::PathBuf;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::api::moon::auth::{AuthenticationError, Channel};
+use crate::api::moon::download::{
+    DownloadFileEndpointData, DownloadRequestError, DownloadResponseData,
+};
+use crate::game::current_time_millis;
+use crate::game::downloads::DownloadTracker;
+use crate::game::process::ProcessRegistry;
+use crate::session::SessionStore;
+use crate::settings::SettingsDocument;
+use crate::storage::location::{version_folder, LauncherPaths};
+use crate::storage::types::{InstallationData, InstalledVersionData};
+use crate::storage::{StorageError, StorageType};
+
+/// All errors which can occur upon installing a channel version
+#[derive(Debug, Serialize, Deserialize)]
+pub enum InstallError {
+    DownloadRequest {
+        message: &'static str,
+        cause: DownloadRequestError,
+    },
+    Authentication {
+        message: &'static str,
+        cause: AuthenticationError,
+    },
+    DownloadFailed {
+        message: &'static str,
+    },
+    FileWrite {
+        message: &'static str,
+    },
+    Location {
+        message: &'static str,
+    },
+    InsufficientSpace {
+        message: &'static str,
+        required: u64,
+        available: u64,
+    },
+    Storage {
+        message: &'static str,
+        cause: StorageError,
+    },
+}
+
+impl InstallError {
+    /// Returns the key of the message describing the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            InstallError::DownloadRequest { message, .. }
+            | InstallError::Authentication { message, .. }
+            | InstallError::DownloadFailed { message }
+            | InstallError::FileWrite { message }
+            | InstallError::Location { message }
+            | InstallError::InsufficientSpace { message, .. }
+            | InstallError::Storage { message, .. } => message,
+        }
+    }
+}
+
+/// Resolves the directory of a version, see [LauncherPaths::version_directory]
+pub fn version_directory(paths: &LauncherPaths, channel: &str, version: &str) -> PathBuf {
+    paths.version_directory(channel, version)
+}
+
+/// Resolves the build file of a version, which is named after its directory
+pub fn version_jar(paths: &LauncherPaths, channel: &str, version: &str) -> PathBuf {
+    let mut jar = version_directory(paths, channel, version);
+    jar.push(format!("{}.jar", version_folder(channel, version)));
+    jar
+}
+
+/// Returns whether the build file of a version exists
+pub fn is_version_on_disk(paths: &LauncherPaths, channel: &str, version: &str) -> bool {
+    version_jar(paths, channel, version).exists()
+}
+
+/// Loads all recorded installations from the working directory
+///
+/// Unlike settings, installations are never replaced by their default if they cannot be loaded,
+/// saving them afterwards would forget every installed version
+pub fn load_installations(paths: &LauncherPaths) -> Result<InstallationData, InstallError> {
+    crate::storage::load_storage_data(
+        paths,
+        StorageType::Installations,
+        InstallationData::default(),
+    )
+    .map(|loaded| loaded.data)
+    .map_err(|cause| InstallError::Storage {
+        message: cause.message(),
+        cause,
+    })
+}
+
+/// Finds the installation of a channel version, files which have been deleted manually are
+/// not considered installed
+pub fn find_installation<'a>(
+    paths: &LauncherPaths,
+    installations: &'a InstallationData,
+    channel: &str,
+    version: &str,
+) -> Option<&'a InstalledVersionData> {
+    installations.installations.iter().find(|installation| {
+        installation.channel == channel
+            && installation.version == version
+            && is_version_on_disk(paths, channel, version)
+    })
+}
+
+/// Checks if the latest version of a channel is installed and up-to-date
+pub fn is_latest_installed(
+    paths: &LauncherPaths,
+    installations: &InstallationData,
+    channel: &Channel,
+) -> bool {
+    is_version_installed(paths, installations, channel, &channel.latest_version)
+}
+
+/// Checks if a version of a channel is installed and up-to-date
+///
+/// Builds might be re-uploaded under the latest version, which is why the `last_updated`
+/// timestamp of the channel is compared against the one recorded on installation. The timestamp
+/// only describes the latest build, older versions are up-to-date as long as they are installed
+pub fn is_version_installed(
+    paths: &LauncherPaths,
+    installations: &InstallationData,
+    channel: &Channel,
+    version: &str,
+) -> bool {
+    match find_installation(paths, installations, &channel.name, version) {
+        Some(installation) if version == channel.latest_version => {
+            installation.last_updated >= channel.last_updated
+        }
+        Some(_) => true,
+        None => false,
+    }
+}
+
+/// Downloads a channel version into its version directory and records the installation
+///
+/// Installations of the same version wait for each other, the version is only downloaded
+/// again if the one installed in the meantime is outdated already
+pub async fn install_version(
+    session: &dyn SessionStore,
+    downloads: &dyn DownloadTracker,
+    processes: &dyn ProcessRegistry,
+    settings: &SettingsDocument,
+    paths: &LauncherPaths,
+    channel: &Channel,
+    version: &str,
+) -> Result<PathBuf, InstallError> {
+    let version_lock = downloads.version_lock(&channel.name, version);
+    let _installing = version_lock.lock().await;
+
+    let installations = load_installations(paths)?;
+    if is_version_installed(paths, &installations, channel, version) {
+        return Ok(version_jar(paths, &channel.name, version));
+    }
+
+    let download = request_download(session, settings, channel, version).await?;
+
+    let endpoint = DownloadFileEndpointData {
+        download_link: download.download_link,
+    };
+    let response = crate::api::requester::create_request(session, endpoint)
+        .await
+        .map_err(|_| InstallError::DownloadFailed {
+            message: "install-download-failed",
+        })?;
+
+    if response.status() != StatusCode::OK {
+        return Err(InstallError::DownloadFailed {
+            message: "install-unexpected-status",
+        });
+    }
+
+    // Refuse the download before writing anything if it wouldn't fit on the disk
+    let required = response.content_length().unwrap_or(0);
+    crate::storage::usage::check_available_space(paths, required).map_err(|error| {
+        InstallError::InsufficientSpace {
+            message: "install-insufficient-space",
+            required: error.required,
+            available: error.available,
+        }
+    })?;
+
+    let content = response
+        .bytes()
+        .await
+        .map_err(|_| InstallError::DownloadFailed {
+            message: "install-download-failed",
+        })?;
+
+    paths
+        .create_minecraft_directory()
+        .map_err(|error| InstallError::Location {
+            message: error.message(),
+        })?;
+    create_dir_all(version_directory(paths, &channel.name, version)).map_err(|_| {
+        InstallError::FileWrite {
+            message: "install-create-directory",
+        }
+    })?;
+
+    // Write to a partial file first so an interrupted download is never considered installed
+    let jar = version_jar(paths, &channel.name, version);
+    let mut partial_jar = jar.clone();
+    partial_jar.set_extension("jar.part");
+    std::fs::write(&partial_jar, content).map_err(|_| InstallError::FileWrite {
+        message: "install-write-file",
+    })?;
+    std::fs::rename(&partial_jar, &jar).map_err(|_| InstallError::FileWrite {
+        message: "install-move-file",
+    })?;
+
+    record_installation(paths, channel, version)?;
+
+    // Failing to clean up old versions should never fail the installation itself
+    let _ = crate::game::retention::collect_old_versions(settings, paths, processes);
+    Ok(jar)
+}
+
+/// Requests the download link of a version
+///
+/// Another launcher signed in as the user might have authenticated in the meantime, which
+/// is why a rejected session is renewed once before giving up
+async fn request_download(
+    session: &dyn SessionStore,
+    settings: &SettingsDocument,
+    channel: &Channel,
+    version: &str,
+) -> Result<DownloadResponseData, InstallError> {
+    let session_token = session.session_token();
+    let request = || {
+        crate::api::moon::download::request_download(
+            session,
+            channel.name.clone(),
+            version.to_string(),
+        )
+    };
+
+    match request().await {
+        Err(DownloadRequestError::InvalidSession { .. }) => {
+            // Another download might have renewed the session already
+            if session.session_token() == session_token {
+                crate::api::moon::auth::renew_session(session, settings.login.uid)
+                    .await
+                    .map_err(|cause| InstallError::Authentication {
+                        message: cause.message(),
+                        cause,
+                    })?;
+            }
+            request().await
+        }
+        result => result,
+    }
+    .map_err(|cause| InstallError::DownloadRequest {
+        message: cause.message(),
+        cause,
+    })
+}
+
+/// Records a freshly installed version, replacing any previous record of it
+fn record_installation(
+    paths: &LauncherPaths,
+    channel: &Channel,
+    version: &str,
+) -> Result<(), InstallError> {
+    let mut installations = load_installations(paths)?;
+    installations.installations.retain(|installation| {
+        !(installation.channel == channel.name && installation.version == version)
+    });
+    installations.installations.push(InstalledVersionData {
+        channel: channel.name.clone(),
+        version: version.to_string(),
+        last_updated: channel.last_updated,
+        installed_at: current_time_millis(),
+    });
+
+    crate::storage::save_storage_data(paths, StorageType::Installations, installations).map_err(
+        |_| InstallError::FileWrite {
+            message: "install-record",
+        },
+    )
+}
@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::downloads::DownloadTracker;
+use crate::game::install::{install_version, is_version_installed, version_jar, InstallError};
+use crate::game::process::{ExitCallback, ProcessRegistry};
+use crate::game::version::ResolvedVersion;
+use crate::game::wine::{is_windows_program, WineRunner};
+use crate::session::SessionStore;
+use crate::settings::SettingsDocument;
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{GameSettingData, VersionSelectionData, WineSettingData};
+
+/// All errors which can occur upon launching a channel
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LaunchError {
+    UnknownChannel {
+        message: &'static str,
+    },
+    UnknownProfile {
+        message: &'static str,
+    },
+    NoVersionAvailable {
+        message: &'static str,
+    },
+    InstallFailed {
+        message: &'static str,
+        cause: InstallError,
+    },
+    Location {
+        message: &'static str,
+    },
+    Hook {
+        message: &'static str,
+    },
+    ProcessSpawn {
+        message: &'static str,
+    },
+    InsufficientMemory {
+        message: &'static str,
+        memory: i64,
+        minimum: i64,
+    },
+}
+
+/// Resolves, installs if missing and launches the version of a selection
+pub async fn launch_selection(
+    session: &dyn SessionStore,
+    downloads: &dyn DownloadTracker,
+    processes: &dyn ProcessRegistry,
+    settings: &SettingsDocument,
+    paths: &LauncherPaths,
+    selection: &VersionSelectionData,
+    options: LaunchOptions,
+) -> Result<ResolvedVersion, LaunchError> {
+    let installations = crate::game::install::load_installations(paths).map_err(|cause| {
+        LaunchError::InstallFailed {
+            message: cause.message(),
+            cause,
+        }
+    })?;
+
+    // Without a session the channels are unknown, installed versions can still be launched
+    if !session.is_signed_in() {
+        let resolved =
+            crate::game::version::resolve_installed_version(paths, &installations, selection)
+                .ok_or(LaunchError::NoVersionAvailable {
+                    message: "launch-not-installed",
+                })?;
+        launch_version(
+            paths,
+            &selection.channel,
+            &resolved.version,
+            options,
+            processes,
+        )?;
+        return Ok(resolved);
+    }
+
+    let channel = session
+        .channel(&selection.channel)
+        .ok_or(LaunchError::UnknownChannel {
+            message: "launch-unknown-channel",
+        })?;
+
+    // Checked before anything is downloaded, the game would only crash once it is running
+    if let Some(minimum) = channel.minimum_memory {
+        if options.memory < minimum {
+            return Err(LaunchError::InsufficientMemory {
+                message: "launch-insufficient-memory",
+                memory: options.memory,
+                minimum,
+            });
+        }
+    }
+
+    let resolved = crate::game::version::resolve_version(&channel, selection).ok_or(
+        LaunchError::NoVersionAvailable {
+            message: "launch-no-version",
+        },
+    )?;
+
+    if !is_version_installed(paths, &installations, &channel, &resolved.version) {
+        install_version(
+            session,
+            downloads,
+            processes,
+            settings,
+            paths,
+            &channel,
+            &resolved.version,
+        )
+        .await
+        .map_err(|cause| LaunchError::InstallFailed {
+            message: cause.message(),
+            cause,
+        })?;
+    }
+
+    launch_version(
+        paths,
+        &selection.channel,
+        &resolved.version,
+        options,
+        processes,
+    )?;
+    Ok(resolved)
+}
+
+/// Everything a version is started with
+#[derive(Clone, Debug)]
+pub struct LaunchOptions {
+    /// The maximum memory (in MiB) of the game
+    pub memory: i64,
+    /// The memory (in MiB) the game starts with
+    pub min_memory: Option<i64>,
+    /// All JVM arguments including the ones of the preset
+    pub jvm_arguments: Vec<String>,
+    /// The directory containing the worlds, options and mods
+    pub game_directory: PathBuf,
+    pub assets_directory: PathBuf,
+    pub window_size: Option<(u32, u32)>,
+    pub fullscreen: bool,
+    pub wrapper: Vec<String>,
+    pub environment: BTreeMap<String, String>,
+    pub pre_launch_hook: Option<String>,
+    pub post_exit_hook: Option<String>,
+    /// Hooks which are Windows programs are started through this runner
+    pub wine: Option<WineRunner>,
+}
+
+impl LaunchOptions {
+    /// The options used when launching a channel without a profile, everything lives inside
+    /// the main minecraft directory
+    pub fn from_settings(
+        paths: &LauncherPaths,
+        settings: &GameSettingData,
+        wine: &WineSettingData,
+    ) -> LaunchOptions {
+        let mut jvm_arguments: Vec<String> = settings
+            .jvm_preset
+            .arguments()
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect();
+        jvm_arguments.extend(settings.jvm_arguments.iter().cloned());
+
+        LaunchOptions {
+            memory: settings.memory,
+            min_memory: settings.min_memory,
+            jvm_arguments,
+            game_directory: paths.minecraft_directory().to_path_buf(),
+            assets_directory: paths.minecraft_directory().join("assets"),
+            window_size: settings.window_width.zip(settings.window_height),
+            fullscreen: settings.fullscreen,
+            wrapper: settings.wrapper.clone(),
+            environment: settings.environment.clone(),
+            pre_launch_hook: settings.pre_launch_hook.clone(),
+            post_exit_hook: settings.post_exit_hook.clone(),
+            wine: WineRunner::from_settings(paths, wine).ok(),
+        }
+    }
+}
+
+/// Starts an installed version using the given launch options
+///
+/// The pre-launch hook has to finish successfully before the game is started. The game is
+/// handed over to the [ProcessRegistry], which runs the post-exit hook once it has exited
+pub fn launch_version(
+    paths: &LauncherPaths,
+    channel: &str,
+    version: &str,
+    options: LaunchOptions,
+    processes: &dyn ProcessRegistry,
+) -> Result<(), LaunchError> {
+    create_dir_all(&options.game_directory).map_err(|_| LaunchError::Location {
+        message: "launch-game-directory",
+    })?;
+
+    if let Some(ref hook) = options.pre_launch_hook {
+        let status = hook_command(hook, &options)
+            .status()
+            .map_err(|_| LaunchError::Hook {
+                message: "launch-pre-launch-spawn",
+            })?;
+        if !status.success() {
+            return Err(LaunchError::Hook {
+                message: "launch-pre-launch-failed",
+            });
+        }
+    }
+
+    // The wrapper becomes the actual process, which then starts java itself
+    let mut command = match options.wrapper.split_first() {
+        Some((wrapper, wrapper_arguments)) => {
+            let mut command = Command::new(wrapper);
+            command.args(wrapper_arguments).arg("java");
+            command
+        }
+        None => Command::new("java"),
+    };
+
+    command.arg(format!("-Xmx{}M", options.memory));
+    if let Some(min_memory) = options.min_memory {
+        command.arg(format!("-Xms{}M", min_memory));
+    }
+    command
+        .args(&options.jvm_arguments)
+        .arg("-jar")
+        .arg(version_jar(paths, channel, version))
+        .arg("--version")
+        .arg(version)
+        .arg("--gameDir")
+        .arg(&options.game_directory)
+        .arg("--assetsDir")
+        .arg(&options.assets_directory);
+    if let Some((width, height)) = options.window_size {
+        command
+            .arg("--width")
+            .arg(width.to_string())
+            .arg("--height")
+            .arg(height.to_string());
+    }
+    if options.fullscreen {
+        command.arg("--fullscreen");
+    }
+
+    let game = command
+        .envs(&options.environment)
+        .current_dir(&options.game_directory)
+        .stdin(Stdio::null())
+        .spawn()
+        .map_err(|_| LaunchError::ProcessSpawn {
+            message: "launch-process-spawn",
+        })?;
+
+    let post_exit_hook = options.post_exit_hook.clone().map(|hook| {
+        Box::new(move || {
+            let _ = hook_command(&hook, &options).status();
+        }) as ExitCallback
+    });
+    processes.watch(game, channel, version, post_exit_hook);
+
+    Ok(())
+}
+
+/// Creates the command running a hook through the shell of the operating system, hooks whose
+/// program is a Windows program are started through the wine runner if there is one
+fn hook_command(hook: &str, options: &LaunchOptions) -> Command {
+    let words = split_hook(hook);
+    let windows_program = words
+        .split_first()
+        .filter(|(program, _)| is_windows_program(Path::new(program)));
+
+    let mut command = match (&options.wine, windows_program) {
+        (Some(wine), Some((program, arguments))) => {
+            let mut command = wine.command(program);
+            command.args(arguments);
+            command
+        }
+        _ if cfg!(windows) => {
+            let mut command = Command::new("cmd");
+            command.arg("/C").arg(hook);
+            command
+        }
+        _ => {
+            let mut command = Command::new("sh");
+            command.arg("-c").arg(hook);
+            command
+        }
+    };
+    command
+        .envs(&options.environment)
+        .current_dir(&options.game_directory)
+        .stdin(Stdio::null());
+    command
+}
+
+/// Splits a hook into its words, quotes keep the spaces of paths like `C:/Program Files`
+///
+/// Backslashes are kept as they are, as they separate the directories of Windows paths
+fn split_hook(hook: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+
+    for character in hook.chars() {
+        match (quote, character) {
+            (Some(open), _) if character == open => quote = None,
+            (Some(_), _) => word.get_or_insert_with(String::new).push(character),
+            (None, '"' | '\'') => {
+                quote = Some(character);
+                word.get_or_insert_with(String::new);
+            }
+            (None, _) if character.is_whitespace() => words.extend(word.take()),
+            (None, _) => word.get_or_insert_with(String::new).push(character),
+        }
+    }
+    words.extend(word);
+    words
+}
@@ -0,0 +1,82 @@
+use serde::Serialize;
+
+use crate::settings::validation::MINIMUM_MEMORY;
+
+/// The memory (in MiB) which should always be left to the operating system and other programs
+pub const RESERVED_SYSTEM_MEMORY: i64 = 2048;
+
+/// The memory (in MiB) used if the memory of the system cannot be determined
+pub const FALLBACK_MEMORY: i64 = 2048;
+
+/// Recommending more than this (in MiB) only makes garbage collection pauses longer
+pub const MAXIMUM_RECOMMENDED_MEMORY: i64 = 8192;
+
+/// The memory of the system, all values are in MiB
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct SystemMemory {
+    pub total: i64,
+    /// The memory which can be used without swapping
+    pub available: i64,
+}
+
+/// The memory of the system alongside the memory recommended for the game, all values are
+/// in MiB
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct MemoryInfo {
+    pub total: i64,
+    pub available: i64,
+    pub recommended: i64,
+    pub minimum: i64,
+}
+
+/// Reads the memory of the system, sys-info reports it in KiB
+pub fn system_memory() -> Option<SystemMemory> {
+    let info = sys_info::mem_info().ok()?;
+    Some(SystemMemory {
+        total: (info.total / 1024) as i64,
+        available: (info.avail / 1024) as i64,
+    })
+}
+
+/// Recommends the memory of the game, half of the total memory as long as it is available
+/// right now, but never less than the channel needs
+///
+/// The recommendation is rounded down to 512 MiB steps
+pub fn recommended_memory(system: Option<SystemMemory>, channel_minimum: Option<i64>) -> i64 {
+    let required = channel_minimum.unwrap_or(0).max(MINIMUM_MEMORY);
+    let system = match system {
+        Some(system) => system,
+        None => return FALLBACK_MEMORY.max(required),
+    };
+
+    let recommended = (system.total / 2)
+        .min(system.available - RESERVED_SYSTEM_MEMORY / 2)
+        .min(MAXIMUM_RECOMMENDED_MEMORY);
+    let recommended = recommended / 512 * 512;
+    recommended.max(required)
+}
+
+/// Describes the memory of the system and the recommendation for the given channel
+pub fn memory_info(channel_minimum: Option<i64>) -> MemoryInfo {
+    let system = system_memory();
+    MemoryInfo {
+        total: system.map(|system| system.total).unwrap_or(0),
+        available: system.map(|system| system.available).unwrap_or(0),
+        recommended: recommended_memory(system, channel_minimum),
+        minimum: channel_minimum.unwrap_or(0).max(MINIMUM_MEMORY),
+    }
+}
+
+/// Warns if an allocation would leave too little memory for everything else, allocations which
+/// don't fit at all are rejected by the validation of the game settings instead
+pub fn memory_warning(memory: i64) -> Option<String> {
+    let system = system_memory()?;
+    let key = if system.total - memory < RESERVED_SYSTEM_MEMORY {
+        "memory-reserved-warning"
+    } else if memory > system.available {
+        "memory-available-warning"
+    } else {
+        return None;
+    };
+    Some(crate::locale::translate(key, &[]))
+}
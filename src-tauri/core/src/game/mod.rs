@@ -0,0 +1,19 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod downloads;
+pub mod install;
+pub mod launch;
+pub mod memory;
+pub mod process;
+pub mod profile;
+pub mod retention;
+pub mod version;
+pub mod wine;
+
+/// Returns the current unix timestamp in milliseconds, the same format the backend uses
+pub fn current_time_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::process::Child;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::Serialize;
+
+use crate::game::current_time_millis;
+
+/// A game process started by the launcher which hasn't exited yet
+#[derive(Clone, Debug, Serialize)]
+pub struct RunningGame {
+    pub pid: u32,
+    pub channel: String,
+    pub version: String,
+    pub started_at: i64,
+}
+
+/// Called once a game has exited, used to run the post-exit hook
+pub type ExitCallback = Box<dyn FnOnce() + Send>;
+
+/// Keeps track of the game processes started by the launcher
+pub trait ProcessRegistry: Send + Sync {
+    /// Takes over a started game, waits for it to exit in the background and then calls
+    /// `on_exit`
+    fn watch(&self, game: Child, channel: &str, version: &str, on_exit: Option<ExitCallback>);
+
+    /// Whether a version of a channel is still used by a running game
+    fn is_running(&self, channel: &str, version: &str) -> bool {
+        self.running()
+            .iter()
+            .any(|game| game.channel == channel && game.version == version)
+    }
+
+    /// All games which are still running
+    fn running(&self) -> Vec<RunningGame>;
+}
+
+/// Waits for every game on its own thread, which also makes sure no exited game is left
+/// behind as a zombie process
+#[derive(Default)]
+pub struct ProcessService {
+    running: Arc<Mutex<HashMap<u32, RunningGame>>>,
+}
+
+impl ProcessRegistry for ProcessService {
+    fn watch(&self, mut game: Child, channel: &str, version: &str, on_exit: Option<ExitCallback>) {
+        let pid = game.id();
+        self.running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                pid,
+                RunningGame {
+                    pid,
+                    channel: channel.to_string(),
+                    version: version.to_string(),
+                    started_at: current_time_millis(),
+                },
+            );
+
+        let running = self.running.clone();
+        std::thread::spawn(move || {
+            let _ = game.wait();
+            running
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(&pid);
+            if let Some(on_exit) = on_exit {
+                on_exit();
+            }
+        });
+    }
+
+    fn running(&self) -> Vec<RunningGame> {
+        self.running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,223 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::launch::LaunchOptions;
+use crate::settings::{SettingsError, SettingsService};
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{
+    GameSettingData, InstanceProfileData, ProfileSettingData, VersionSelectionData, WineSettingData,
+};
+
+/// The directory inside the minecraft directory all instance directories are created in
+pub const INSTANCES_DIRECTORY: &str = "instances";
+
+/// All errors which can occur upon managing profiles
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ProfileError {
+    UnknownProfile {
+        message: &'static str,
+    },
+    FileWrite {
+        message: &'static str,
+    },
+    Settings {
+        message: &'static str,
+        cause: SettingsError,
+    },
+}
+
+impl From<SettingsError> for ProfileError {
+    fn from(cause: SettingsError) -> Self {
+        ProfileError::Settings {
+            message: cause.message(),
+            cause,
+        }
+    }
+}
+
+const UNKNOWN_PROFILE: ProfileError = ProfileError::UnknownProfile {
+    message: "profile-unknown",
+};
+
+/// Resolves the game directory of a profile
+pub fn instance_directory(paths: &LauncherPaths, profile: &InstanceProfileData) -> PathBuf {
+    match profile.game_directory {
+        Some(ref directory) => directory.clone(),
+        None => paths
+            .minecraft_directory()
+            .join(INSTANCES_DIRECTORY)
+            .join(&profile.id),
+    }
+}
+
+/// Builds the options a profile is launched with, the game settings are used for everything
+/// the profile doesn't set itself
+pub fn launch_options(
+    paths: &LauncherPaths,
+    profile: &InstanceProfileData,
+    game: &GameSettingData,
+    wine: &WineSettingData,
+) -> LaunchOptions {
+    let mut options = LaunchOptions::from_settings(paths, game, wine);
+    options.game_directory = instance_directory(paths, profile);
+    if !profile.shared_assets {
+        options.assets_directory = options.game_directory.join("assets");
+    }
+    if let Some(memory) = profile.memory {
+        options.memory = memory;
+        // Java refuses to start if the initial memory exceeds the maximum memory
+        options.min_memory = options
+            .min_memory
+            .filter(|min_memory| *min_memory <= memory);
+    }
+
+    // The arguments of the profile come last, so they win over the ones of the game settings
+    options
+        .jvm_arguments
+        .extend(profile.jvm_arguments.iter().cloned());
+    options
+}
+
+/// Derives an unused profile id from a name, the id is used as a directory name so it only
+/// contains lowercase letters, digits and dashes
+fn unique_profile_id(profiles: &ProfileSettingData, name: &str) -> String {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug.trim_matches('-');
+    let base = if slug.is_empty() { "profile" } else { slug };
+
+    let mut id = base.to_string();
+    let mut suffix = 2;
+    while profiles.find(&id).is_some() {
+        id = format!("{base}-{suffix}");
+        suffix += 1;
+    }
+    id
+}
+
+/// Creates a new profile which always uses the latest version of a channel
+pub fn create_profile(
+    settings: &SettingsService,
+    name: &str,
+    channel: &str,
+) -> Result<InstanceProfileData, ProfileError> {
+    let mut created = None;
+    settings.update(|profiles: &mut ProfileSettingData| {
+        let profile = InstanceProfileData {
+            id: unique_profile_id(profiles, name),
+            name: name.trim().to_string(),
+            selection: VersionSelectionData {
+                channel: channel.to_string(),
+                ..VersionSelectionData::default()
+            },
+            ..InstanceProfileData::default()
+        };
+        profiles.profiles.push(profile.clone());
+        created = Some(profile);
+    })?;
+    created.ok_or(UNKNOWN_PROFILE)
+}
+
+/// Creates a copy of a profile including its instance directory
+///
+/// The copy always gets its own instance directory, even if the original uses a custom one
+pub fn clone_profile(
+    settings: &SettingsService,
+    paths: &LauncherPaths,
+    id: &str,
+    name: &str,
+) -> Result<InstanceProfileData, ProfileError> {
+    let profiles = settings.get::<ProfileSettingData>();
+    let original = profiles.find(id).ok_or(UNKNOWN_PROFILE)?;
+    let clone = InstanceProfileData {
+        id: unique_profile_id(&profiles, name),
+        name: name.trim().to_string(),
+        game_directory: None,
+        ..original.clone()
+    };
+
+    let original_directory = instance_directory(paths, original);
+    if original_directory.is_dir() {
+        crate::storage::copy_directory(&original_directory, &instance_directory(paths, &clone))
+            .map_err(|_| ProfileError::FileWrite {
+                message: "profile-copy-directory",
+            })?;
+    }
+
+    settings.update(|profiles: &mut ProfileSettingData| profiles.profiles.push(clone.clone()))?;
+    Ok(clone)
+}
+
+/// Renames a profile, its id and therefore its instance directory stay the same
+pub fn rename_profile(
+    settings: &SettingsService,
+    id: &str,
+    name: &str,
+) -> Result<InstanceProfileData, ProfileError> {
+    let mut renamed = None;
+    settings.update(|profiles: &mut ProfileSettingData| {
+        if let Some(profile) = profiles.find_mut(id) {
+            profile.name = name.trim().to_string();
+            renamed = Some(profile.clone());
+        }
+    })?;
+    renamed.ok_or(UNKNOWN_PROFILE)
+}
+
+/// Replaces the settings of a profile, the id of the profile can't be changed
+pub fn update_profile(
+    settings: &SettingsService,
+    profile: InstanceProfileData,
+) -> Result<InstanceProfileData, ProfileError> {
+    let mut updated = false;
+    settings.update(|profiles: &mut ProfileSettingData| {
+        if let Some(existing) = profiles.find_mut(&profile.id) {
+            *existing = profile.clone();
+            updated = true;
+        }
+    })?;
+
+    if !updated {
+        return Err(UNKNOWN_PROFILE);
+    }
+    Ok(profile)
+}
+
+/// Deletes a profile and optionally its instance directory
+///
+/// Custom game directories are never removed, they might contain anything
+pub fn delete_profile(
+    settings: &SettingsService,
+    paths: &LauncherPaths,
+    id: &str,
+    delete_files: bool,
+) -> Result<(), ProfileError> {
+    let profiles = settings.get::<ProfileSettingData>();
+    let profile = profiles.find(id).ok_or(UNKNOWN_PROFILE)?;
+
+    if delete_files && profile.game_directory.is_none() {
+        // Profiles edited by hand are never validated, their id might point anywhere
+        let instances = paths.minecraft_directory().join(INSTANCES_DIRECTORY);
+        let directory = instance_directory(paths, profile);
+        if !crate::storage::is_directory_name(&profile.id) || !directory.starts_with(&instances) {
+            return Err(ProfileError::FileWrite {
+                message: "profile-invalid-directory",
+            });
+        }
+        if directory.exists() {
+            std::fs::remove_dir_all(&directory).map_err(|_| ProfileError::FileWrite {
+                message: "profile-remove-directory",
+            })?;
+        }
+    }
+
+    settings.update(|profiles: &mut ProfileSettingData| {
+        profiles.profiles.retain(|profile| profile.id != id)
+    })?;
+    Ok(())
+}
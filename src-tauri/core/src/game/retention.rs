@@ -0,0 +1,150 @@
+use std::cmp::Reverse;
+use std::fs::remove_dir_all;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::install::{
+    is_version_on_disk, load_installations, version_directory, InstallError,
+};
+use crate::game::process::ProcessRegistry;
+use crate::settings::{SettingsDocument, SettingsError};
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{InstallationData, InstalledVersionData, VersionSelectionData};
+use crate::storage::StorageType;
+
+/// All errors which can occur upon rolling back a channel
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RollbackError {
+    NoPreviousVersion {
+        message: &'static str,
+    },
+    SelectionSave {
+        message: &'static str,
+        cause: SettingsError,
+    },
+}
+
+/// Describes what has been removed by [collect_old_versions]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CollectionReport {
+    /// The installations which have been removed, of every channel
+    pub removed_versions: Vec<InstalledVersionData>,
+    pub freed_bytes: u64,
+}
+
+/// Returns all installations of a channel whose files are still present, newest first
+pub fn channel_installations<'a>(
+    paths: &LauncherPaths,
+    installations: &'a InstallationData,
+    channel: &str,
+) -> Vec<&'a InstalledVersionData> {
+    let mut channel_installations: Vec<&InstalledVersionData> = installations
+        .installations
+        .iter()
+        .filter(|installation| {
+            installation.channel == channel
+                && is_version_on_disk(paths, channel, &installation.version)
+        })
+        .collect();
+    channel_installations.sort_by_key(|installation| Reverse(installation.installed_at));
+    channel_installations
+}
+
+/// Finds the installation which was installed right before the one currently in use
+///
+/// This only looks at the local installations, the network is never touched. Without a
+/// pinned version the newest installation is considered to be the one in use
+pub fn find_previous_installation(
+    paths: &LauncherPaths,
+    installations: &InstallationData,
+    selection: &VersionSelectionData,
+) -> Option<InstalledVersionData> {
+    let installed = channel_installations(paths, installations, &selection.channel);
+    let current_index = if selection.requires_latest || selection.preferred_version.is_empty() {
+        0
+    } else {
+        installed
+            .iter()
+            .position(|installation| installation.version == selection.preferred_version)?
+    };
+
+    installed
+        .get(current_index + 1)
+        .map(|installation| (*installation).clone())
+}
+
+/// Removes all versions of every channel beyond the retention limit, oldest installations are
+/// removed first
+///
+/// Pinned versions, including the ones pinned by profiles, are never removed as the user
+/// explicitly chose to use them. Neither are versions of games which are still running
+pub fn collect_old_versions(
+    settings: &SettingsDocument,
+    paths: &LauncherPaths,
+    processes: &dyn ProcessRegistry,
+) -> Result<CollectionReport, InstallError> {
+    let retained_versions = settings.updates.retained_versions.max(1);
+    let pinned_versions: Vec<&VersionSelectionData> = settings
+        .versions
+        .selections
+        .iter()
+        .chain(
+            settings
+                .profiles
+                .profiles
+                .iter()
+                .map(|profile| &profile.selection),
+        )
+        .filter(|selection| !selection.requires_latest)
+        .collect();
+
+    let mut installations = load_installations(paths)?;
+    let mut channels: Vec<String> = installations
+        .installations
+        .iter()
+        .map(|installation| installation.channel.clone())
+        .collect();
+    channels.sort();
+    channels.dedup();
+
+    let mut outdated = Vec::new();
+    for channel in channels {
+        for installation in channel_installations(paths, &installations, &channel)
+            .into_iter()
+            .skip(retained_versions)
+        {
+            let pinned = pinned_versions.iter().any(|selection| {
+                selection.channel == installation.channel
+                    && selection.preferred_version == installation.version
+            });
+            if !pinned && !processes.is_running(&installation.channel, &installation.version) {
+                outdated.push(installation.clone());
+            }
+        }
+    }
+
+    let mut report = CollectionReport::default();
+    for installation in outdated {
+        installations.installations.retain(|existing| {
+            !(existing.channel == installation.channel && existing.version == installation.version)
+        });
+
+        let directory = version_directory(paths, &installation.channel, &installation.version);
+        let size = crate::storage::directory_size(&directory);
+
+        // Keep the record if the files could not be removed so it is retried next time
+        if remove_dir_all(&directory).is_err() {
+            installations.installations.push(installation);
+            continue;
+        }
+        report.freed_bytes += size;
+        report.removed_versions.push(installation);
+    }
+
+    crate::storage::save_storage_data(paths, StorageType::Installations, installations).map_err(
+        |_| InstallError::FileWrite {
+            message: "retention-record",
+        },
+    )?;
+    Ok(report)
+}
@@ -0,0 +1,120 @@
+use serde::Serialize;
+
+use crate::api::moon::auth::Channel;
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{InstallationData, VersionSelectionData};
+
+/// The version which will actually be launched for a channel
+#[derive(Clone, Debug, Serialize)]
+pub struct ResolvedVersion {
+    pub version: String,
+    /// Set if the selection could not be honored and a fallback was used instead
+    pub warning: Option<String>,
+}
+
+/// Resolves the version to launch for a channel using the users selection
+///
+/// ### Fallback policy
+/// - Latest: The latest version of the channel, or the most recently released one if the
+///   server points to a version which is not available (anymore)
+/// - Pinned: The preferred version if it is still available. Versions are only pulled from the
+///   server for a reason, so a pulled version is never launched, the latest version is used instead
+///
+/// None is only returned if the channel has no versions available at all
+pub fn resolve_version(
+    channel: &Channel,
+    selection: &VersionSelectionData,
+) -> Option<ResolvedVersion> {
+    let latest = resolve_latest_version(channel)?;
+
+    // An empty preferred version means the user has never pinned one
+    if selection.requires_latest || selection.preferred_version.is_empty() {
+        return Some(ResolvedVersion {
+            version: latest,
+            warning: None,
+        });
+    }
+
+    if is_version_available(channel, &selection.preferred_version) {
+        return Some(ResolvedVersion {
+            version: selection.preferred_version.clone(),
+            warning: None,
+        });
+    }
+
+    Some(ResolvedVersion {
+        warning: Some(crate::locale::translate(
+            "version-pinned-unavailable",
+            &[
+                ("pinned", selection.preferred_version.clone()),
+                ("latest", latest.clone()),
+            ],
+        )),
+        version: latest,
+    })
+}
+
+/// Resolves the version to launch for a channel from the installed versions only, used while
+/// the channels are unknown as the user is not signed in
+///
+/// The pinned version is launched if it is installed, otherwise the newest installed version.
+/// None is only returned if no version of the channel is installed at all
+pub fn resolve_installed_version(
+    paths: &LauncherPaths,
+    installations: &InstallationData,
+    selection: &VersionSelectionData,
+) -> Option<ResolvedVersion> {
+    let installed =
+        crate::game::retention::channel_installations(paths, installations, &selection.channel);
+    let newest = installed.first()?.version.clone();
+
+    if selection.requires_latest || selection.preferred_version.is_empty() {
+        return Some(ResolvedVersion {
+            version: newest,
+            warning: None,
+        });
+    }
+
+    if installed
+        .iter()
+        .any(|installation| installation.version == selection.preferred_version)
+    {
+        return Some(ResolvedVersion {
+            version: selection.preferred_version.clone(),
+            warning: None,
+        });
+    }
+
+    Some(ResolvedVersion {
+        warning: Some(crate::locale::translate(
+            "version-pinned-not-installed",
+            &[
+                ("pinned", selection.preferred_version.clone()),
+                ("installed", newest.clone()),
+            ],
+        )),
+        version: newest,
+    })
+}
+
+/// Checks if a version is still available for download inside a channel
+pub fn is_version_available(channel: &Channel, version: &str) -> bool {
+    channel
+        .available_versions
+        .iter()
+        .any(|available| available.id == version)
+}
+
+/// Resolves the latest version of a channel, falling back to the most recently released
+/// version if the latest one is not available
+fn resolve_latest_version(channel: &Channel) -> Option<String> {
+    if is_version_available(channel, &channel.latest_version) {
+        return Some(channel.latest_version.clone());
+    }
+
+    channel
+        .available_versions
+        .iter()
+        .max_by_key(|available| available.released_at)
+        .map(|available| available.id.clone())
+}
@@ -0,0 +1,167 @@
+use std::ffi::OsStr;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use directories::BaseDirs;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{WineRunnerKind, WineSettingData};
+
+/// The directory inside the working directory the default prefix is created in
+pub const DEFAULT_PREFIX_DIRECTORY: &str = "wine-prefix";
+
+/// All errors which can occur upon running Windows-only components
+#[derive(Debug, Serialize, Deserialize)]
+pub enum WineError {
+    Disabled { message: &'static str },
+    Unsupported { message: &'static str },
+    Location { message: &'static str },
+    PrefixCreation { message: &'static str },
+    ProcessSpawn { message: &'static str },
+}
+
+/// Starts Windows-only components through the configured wine or proton runner
+#[derive(Clone, Debug)]
+pub struct WineRunner {
+    settings: WineSettingData,
+    prefix: PathBuf,
+}
+
+impl WineRunner {
+    /// Creates the runner from the wine settings, the runner is only available if it has been
+    /// enabled and the launcher doesn't run on Windows itself
+    pub fn from_settings(
+        paths: &LauncherPaths,
+        settings: &WineSettingData,
+    ) -> Result<WineRunner, WineError> {
+        if cfg!(windows) {
+            return Err(WineError::Unsupported {
+                message: "wine-unsupported",
+            });
+        }
+
+        if !settings.enabled {
+            return Err(WineError::Disabled {
+                message: "wine-disabled",
+            });
+        }
+
+        let prefix = match settings.prefix {
+            Some(ref prefix) => prefix.clone(),
+            None => paths.working_directory().join(DEFAULT_PREFIX_DIRECTORY),
+        };
+        Ok(WineRunner {
+            settings: settings.clone(),
+            prefix,
+        })
+    }
+
+    pub fn prefix(&self) -> &Path {
+        &self.prefix
+    }
+
+    /// Creates the prefix directory and lets the runner initialise it, which might take a while
+    /// on the first run
+    ///
+    /// The runner is waited for on a blocking thread, so the async runtime is never stalled
+    pub async fn initialize_prefix(&self) -> Result<(), WineError> {
+        let runner = self.clone();
+        tokio::task::spawn_blocking(move || runner.initialize_prefix_blocking())
+            .await
+            .map_err(|_| WineError::PrefixCreation {
+                message: "wine-prefix-init",
+            })?
+    }
+
+    fn initialize_prefix_blocking(&self) -> Result<(), WineError> {
+        create_dir_all(&self.prefix).map_err(|_| WineError::Location {
+            message: "wine-prefix-creation",
+        })?;
+
+        let status = self
+            .command("wineboot")
+            .arg("--init")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_err(|_| WineError::ProcessSpawn {
+                message: "wine-runner-spawn",
+            })?;
+
+        if !status.success() {
+            return Err(WineError::PrefixCreation {
+                message: "wine-prefix-init",
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates the command starting a Windows program inside the prefix
+    pub fn command(&self, program: impl AsRef<OsStr>) -> Command {
+        let mut command = Command::new(self.binary());
+        if self.settings.runner == WineRunnerKind::Proton {
+            command.arg("run");
+        }
+        command.arg(program);
+
+        match self.settings.runner {
+            WineRunnerKind::Wine => {
+                command.env("WINEPREFIX", &self.prefix);
+                if self.settings.esync {
+                    command.env("WINEESYNC", "1");
+                }
+            }
+            // Proton keeps its actual prefix inside the compatibility data directory. Some of its
+            // tools look for Steam, which is only passed on if it is actually installed
+            WineRunnerKind::Proton => {
+                command.env("STEAM_COMPAT_DATA_PATH", &self.prefix);
+                if let Some(steam) = steam_directory() {
+                    command.env("STEAM_COMPAT_CLIENT_INSTALL_PATH", steam);
+                }
+                if !self.settings.esync {
+                    command.env("PROTON_NO_ESYNC", "1");
+                }
+                if !self.settings.dxvk {
+                    command.env("PROTON_USE_WINED3D", "1");
+                }
+            }
+        }
+
+        command.envs(&self.settings.environment);
+        command
+    }
+
+    fn binary(&self) -> PathBuf {
+        self.settings
+            .binary
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("wine"))
+    }
+}
+
+/// Finds the installation of Steam, either the native or the one of the flatpak
+fn steam_directory() -> Option<PathBuf> {
+    let base_directories = BaseDirs::new()?;
+    let home = base_directories.home_dir();
+    [
+        home.join(".steam").join("steam"),
+        base_directories.data_dir().join("Steam"),
+        home.join(".var/app/com.valvesoftware.Steam/data/Steam"),
+    ]
+    .into_iter()
+    .find(|directory| directory.is_dir())
+}
+
+/// Returns whether a program can only be started on Windows
+pub fn is_windows_program(program: &Path) -> bool {
+    program
+        .extension()
+        .map(|extension| {
+            extension.eq_ignore_ascii_case("exe")
+                || extension.eq_ignore_ascii_case("bat")
+                || extension.eq_ignore_ascii_case("msi")
+        })
+        .unwrap_or(false)
+}
@@ -0,0 +1,17 @@
+//! The core of the launcher, shared by the gui and the command line interface
+//!
+//! Nothing in here depends on tauri, so the core builds and runs without the libraries of a
+//! desktop environment. The state of the launcher is split into services which
+//! are locked independently, like the [session::SessionService] and the
+//! [settings::SettingsService], every operation takes the services it needs as arguments.
+//! Errors leave the core as an [error::LauncherError], whose message is translated by [locale]
+
+pub mod api;
+pub mod error;
+pub mod game;
+pub mod locale;
+pub mod proprietary;
+pub mod serial;
+pub mod session;
+pub mod settings;
+pub mod storage;
@@ -0,0 +1,290 @@
+# Deutsche Texte des Launchers, fehlende Texte werden aus dem englischen Katalog genommen
+
+## Anmeldung und die Server des Launchers
+
+server-unreachable = Die Server konnten nicht erreicht werden, bitte überprüfe deine Verbindung
+server-internal-error = Interner Serverfehler, bitte erstelle ein Ticket
+server-rate-limited = Du hast zu viele Anfragen gestellt, bitte warte eine Minute
+unknown-error = Ein unbekannter Fehler ist aufgetreten, bitte erstelle ein Issue auf GitHub
+auth-invalid-request = Ungültige Anmeldeanfrage, bitte erstelle ein Issue auf GitHub
+auth-invalid-response = Ungültige Antwort auf die Anmeldung erhalten, bitte erstelle ein Issue auf GitHub
+auth-invalid-account = Bitte stelle sicher, dass du über den Discord-Bot sowohl eine HWID als auch einen Benutzernamen festgelegt hast
+auth-hwid-mismatch = Deine HWID stimmt nicht überein, bitte prüfe, was sich geändert hat, und beantrage ein HWID-Reset
+auth-no-user = Es wurde kein Benutzer mit der eingegebenen UID gefunden, bitte prüfe, ob du deine UID richtig eingegeben hast
+auth-serial-unavailable = Die Seriennummer deines Geräts konnte nicht geladen werden, bitte starte den Launcher neu, um es erneut zu versuchen
+hwid-invalid-request = Ungültige Anfrage für ein HWID-Reset, bitte erstelle ein Issue auf GitHub
+hwid-invalid-response = Ungültige Antwort auf die Anfrage für ein HWID-Reset erhalten, bitte erstelle ein Issue auf GitHub
+hwid-already-requested = Du hast bereits ein HWID-Reset beantragt, bitte warte, bis es geprüft wurde
+hwid-no-request = Du hast noch kein HWID-Reset beantragt
+
+## Downloads und Installationen
+
+download-unreachable = Die Downloadserver konnten nicht erreicht werden, bitte überprüfe deine Verbindung
+download-invalid-response = Ungültige Antwort der Downloadserver erhalten, bitte erstelle ein Issue auf GitHub
+download-invalid-session = Deine Sitzung ist abgelaufen, bitte starte den Launcher neu
+download-insufficient-permissions = Du hast nicht die nötigen Rechte, um diesen Kanal herunterzuladen
+install-download-failed = Die Version konnte nicht heruntergeladen werden, bitte überprüfe deine Verbindung
+install-unexpected-status = Der Downloadserver hat einen unerwarteten Statuscode zurückgegeben, bitte erstelle ein Issue auf GitHub
+install-insufficient-space = Es ist nicht genug Speicherplatz frei, um diese Version zu installieren
+install-create-directory = Das Verzeichnis der Version konnte nicht erstellt werden
+install-write-file = Die Datei der Version konnte nicht geschrieben werden
+install-move-file = Die Datei der Version konnte nicht an ihren Platz verschoben werden
+install-record = Die Installation konnte nicht gespeichert werden
+retention-record = Die entfernten Installationen konnten nicht gespeichert werden
+rollback-no-previous-version = Es gibt keine zuvor installierte Version, zu der zurückgekehrt werden kann
+rollback-selection-save = Die Versionsauswahl dieses Kanals konnte nicht gespeichert werden
+usage-remove-directory = Das Verzeichnis konnte nicht entfernt werden, stelle sicher, dass das Spiel geschlossen ist
+usage-shared-category = Diese Dateien werden eventuell auch von anderen Launchern verwendet und können nicht bereinigt werden
+
+## Starten des Spiels
+
+launch-unknown-channel = Du hast keinen Zugriff auf diesen Kanal, bitte starte den Launcher neu
+launch-no-version = Für diesen Kanal sind noch keine Versionen verfügbar
+launch-not-installed = Keine Version dieses Kanals ist installiert, bitte melde dich an, um eine herunterzuladen
+launch-game-directory = Das Spielverzeichnis konnte nicht erstellt werden, bitte überprüfe seine Berechtigungen
+launch-pre-launch-spawn = Der Befehl vor dem Start konnte nicht ausgeführt werden
+launch-pre-launch-failed = Der Befehl vor dem Start ist fehlgeschlagen, das Spiel wurde nicht gestartet
+launch-process-spawn = Das Spiel konnte nicht gestartet werden, bitte stelle sicher, dass Java und der Wrapper installiert sind
+launch-insufficient-memory = Dieser Kanal benötigt mindestens { $minimum } MB Arbeitsspeicher, es sind aber nur { $memory } MB zugewiesen
+memory-reserved-warning = Damit bleiben weniger als 2 GB Arbeitsspeicher für dein Betriebssystem, was deinen ganzen Computer verlangsamen kann
+memory-available-warning = Das ist mehr Arbeitsspeicher als momentan frei ist, schließe andere Programme vor dem Start
+
+## Profile und Verzeichnisse
+
+profile-unknown = Dieses Profil existiert nicht mehr
+profile-copy-directory = Das Instanzverzeichnis konnte nicht kopiert werden, bitte überprüfe deinen Speicherplatz
+profile-remove-directory = Das Instanzverzeichnis konnte nicht entfernt werden, stelle sicher, dass das Spiel geschlossen ist
+profile-invalid-directory = Das Instanzverzeichnis dieses Profils liegt außerhalb des Instanzordners und wurde nicht entfernt
+directory-missing = Dieses Verzeichnis existiert noch nicht, es wird erstellt, sobald es benötigt wird
+directory-open = Das Verzeichnis konnte nicht in deinem Dateimanager geöffnet werden
+
+## Wine
+
+wine-unsupported = Windows-Programme können unter Windows direkt gestartet werden
+wine-disabled = Bitte aktiviere Wine in den Einstellungen, um Windows-Programme auszuführen
+wine-prefix-creation = Das Wine-Präfix konnte nicht erstellt werden, bitte überprüfe seine Berechtigungen
+wine-runner-spawn = Der Runner konnte nicht gestartet werden, bitte überprüfe das eingestellte Programm
+wine-prefix-init = Der Runner konnte das Wine-Präfix nicht initialisieren
+
+## Die proprietäre Bibliothek und die Seriennummer des Geräts
+
+proprietary-different-library = Das Manifest beschreibt eine andere proprietäre Bibliothek, bitte versuche, sie erneut herunterzuladen
+proprietary-hash-mismatch = Die proprietäre Bibliothek stimmt nicht mit ihrem signierten Hash überein, bitte versuche, sie erneut herunterzuladen
+proprietary-read = Die proprietäre Bibliothek konnte nicht gelesen werden, bitte versuche, sie erneut herunterzuladen
+proprietary-invalid-signature = Das Manifest der proprietären Bibliothek ist nicht korrekt signiert, bitte versuche, sie erneut herunterzuladen
+proprietary-damaged = Die proprietäre Bibliothek ist veraltet oder beschädigt, bitte versuche, sie erneut herunterzuladen
+proprietary-invalid-serial = Die proprietäre Bibliothek hat eine ungültige Seriennummer zurückgegeben, bitte erstelle ein Issue auf GitHub
+proprietary-serial-os = Die Seriennummer deines Geräts konnte nicht vom Betriebssystem gelesen werden, bitte erstelle ein Issue auf GitHub
+proprietary-serial-rejected = Die proprietäre Bibliothek hat die Anfrage nach der Seriennummer abgelehnt, bitte erstelle ein Issue auf GitHub
+proprietary-remove-old = Die alte proprietäre Bibliothek konnte nicht entfernt werden, bitte überprüfe die Berechtigungen des Launcher-Verzeichnisses
+proprietary-load = Die proprietäre Bibliothek konnte nicht geladen werden, bitte erstelle ein Issue auf GitHub
+proprietary-abi-mismatch = Die proprietäre Bibliothek passt nicht zu dieser Launcher-Version, bitte aktualisiere den Launcher
+proprietary-manifest-missing = Das Manifest der proprietären Bibliothek fehlt
+proprietary-manifest-read = Das Manifest der proprietären Bibliothek konnte nicht gelesen werden, bitte erstelle ein Issue auf GitHub
+proprietary-download-failed = Die proprietäre Bibliothek konnte nicht heruntergeladen werden, bitte überprüfe deine Verbindung
+proprietary-unexpected-status = Der Downloadserver hat für die proprietäre Bibliothek einen unerwarteten Statuscode zurückgegeben, bitte erstelle ein Issue auf GitHub
+proprietary-write = Die proprietäre Bibliothek konnte nicht geschrieben werden, bitte überprüfe die Berechtigungen des Launcher-Verzeichnisses
+proprietary-move = Die proprietäre Bibliothek konnte nicht an ihren Platz verschoben werden, bitte überprüfe die Berechtigungen des Launcher-Verzeichnisses
+serial-read = Die Seriennummer deines Geräts konnte nicht gelesen werden, bitte erstelle ein Issue auf GitHub
+serial-linux-only = Der Linux-Seriennummernanbieter funktioniert nur unter Linux
+serial-unavailable = Die Machine-ID konnte nicht gelesen werden
+
+## Einstellungen
+
+bundle-read = Das Einstellungspaket konnte nicht gelesen werden
+bundle-invalid = Die Datei ist kein gültiges Einstellungspaket
+bundle-newer-version = Dieses Einstellungspaket wurde von einem neueren Launcher erstellt, bitte aktualisiere
+bundle-serialize = Das Einstellungspaket konnte nicht serialisiert werden
+bundle-write = Das Einstellungspaket konnte nicht geschrieben werden
+settings-reset-installations = Installationen können nur zurückgesetzt werden, indem der Versionsspeicher bereinigt wird
+settings-reset-hardware = Der Hardware-Fingerabdruck ist keine Einstellung und kann nicht zurückgesetzt werden
+settings-invalid-uid = Bitte gib eine gültige UID ein
+settings-invalid-minimum-memory = Der minimale Arbeitsspeicher muss zwischen 1 MB und dem zugewiesenen Arbeitsspeicher liegen
+settings-relative-game-directory = Das Spielverzeichnis muss ein absoluter Pfad sein
+settings-invalid-window-size = Die Fenstergröße muss größer als null sein
+settings-incomplete-window-size = Bitte lege sowohl die Breite als auch die Höhe des Fensters fest
+settings-empty-wrapper-argument = Der Wrapper-Befehl enthält ein leeres Argument
+settings-empty-hook = Hooks dürfen nicht leer sein, entferne sie stattdessen
+settings-invalid-environment = Namen von Umgebungsvariablen dürfen nicht leer sein oder '=' enthalten
+settings-too-little-memory = Das Spiel benötigt mindestens 512 MB Arbeitsspeicher
+settings-too-much-memory = Das ist mehr Arbeitsspeicher, als dein Computer hat
+settings-channel-memory = Dieser Kanal benötigt mehr als den zugewiesenen Arbeitsspeicher
+settings-empty-jvm-argument = Die JVM-Argumente enthalten ein leeres Argument
+settings-jvm-maximum-memory = Bitte nutze die Arbeitsspeicher-Einstellung statt -Xmx
+settings-jvm-minimum-memory = Bitte nutze die Einstellung für den minimalen Arbeitsspeicher statt -Xms
+settings-jvm-garbage-collector = Der Garbage Collector wird bereits durch die JVM-Voreinstellung gewählt
+settings-selection-channel = Jede Versionsauswahl benötigt einen Kanal
+settings-duplicate-selection = Ein Kanal kann nur eine Versionsauswahl haben
+settings-update-interval = Nach Updates kann höchstens alle 5 Minuten gesucht werden
+settings-keep-versions = Mindestens eine installierte Version muss behalten werden
+settings-proton-script = Bitte wähle das Proton-Skript, mit dem Windows-Programme ausgeführt werden sollen
+settings-relative-runner = Das Programm des Runners muss ein absoluter Pfad sein
+settings-relative-prefix = Das Wine-Präfix muss ein absoluter Pfad sein
+settings-invalid-locale = Bitte wähle eine gültige Sprache
+settings-profile-id = Jedes Profil benötigt eine eindeutige ID, die ein gültiger Ordnername ist
+settings-profile-name = Bitte gib einen Namen für das Profil ein
+settings-profile-channel = Jedes Profil benötigt einen Kanal
+settings-restored-backup = { $file } war beschädigt, die zuletzt gespeicherte Version wurde wiederhergestellt
+settings-load-failed = { $file } konnte nicht geladen werden, bis zur nächsten Änderung werden die Standardeinstellungen verwendet
+
+## Speicher
+
+storage-serialize = Die Daten konnten nicht serialisiert werden
+storage-deserialize = Der Inhalt der Speicherdatei konnte nicht gelesen werden
+storage-invalid-object = Die Speicherdatei enthält kein gültiges JSON-Objekt
+storage-create-temporary = Die temporäre Speicherdatei konnte nicht erstellt werden
+storage-write-temporary = Die temporäre Speicherdatei konnte nicht geschrieben werden
+storage-back-up = Die vorherige Speicherdatei konnte nicht gesichert werden
+storage-replace = Die Speicherdatei konnte nicht ersetzt werden
+storage-open = Die Speicherdatei konnte nicht geöffnet werden
+location-working-directory = Das Launcher-Verzeichnis konnte nicht erstellt werden, bitte überprüfe seine Berechtigungen
+location-home-missing = Dein Benutzerverzeichnis konnte nicht gefunden werden
+
+## Kommandozeile
+
+cli-not-logged-in = Du hast dich noch nicht angemeldet, bitte führe zuerst den Befehl login aus
+cli-no-channel-access = Du hast keinen Zugriff auf diesen Kanal
+cli-settings-serialize = Die Einstellungen konnten nicht serialisiert werden, bitte erstelle ein Issue auf GitHub
+cli-unknown-setting = Es gibt keine Einstellung mit diesem Namen
+cli-setting-name = Einstellungen werden über ihren vollständigen Namen wie game.memory geändert
+cli-unknown-section = Es gibt keinen Einstellungsbereich mit diesem Namen
+cli-invalid-value = Der Wert passt nicht zu dieser Einstellung
+cli-error = Fehler: { $message }
+cli-warning = Warnung: { $message }
+cli-logged-in = Angemeldet als { $username }, verfügbare Kanäle: { $channels }
+cli-latest = { " " }(neueste)
+cli-installed = { $channel } { $version } wurde nach { $path } installiert
+cli-launched = { $channel } { $version } wurde gestartet
+cli-file-ok = ok	{ $file }
+cli-file-broken = beschädigt	{ $file }
+
+## Details von Fehlern
+
+detail-http-status = HTTP-Status { $status }
+detail-error-code = Fehlercode { $code }
+detail-abi-version = ABI-Version { $expected } erwartet, { $found } gefunden
+detail-invalid-setting = Ungültige Einstellung: { $field }
+detail-disk-space = { $required } Bytes werden benötigt, { $available } Bytes sind frei
+
+## Versionen und Updates
+
+version-pinned-unavailable = Deine festgelegte Version { $pinned } ist nicht mehr verfügbar, stattdessen wird { $latest } gestartet
+version-pinned-not-installed = Deine festgelegte Version { $pinned } ist nicht installiert, stattdessen wird { $installed } gestartet
+version-missing = Die installierte Version fehlt
+update-title = Update verfügbar
+update-available = { $channel } { $version } ist jetzt verfügbar
+update-downloaded = { $channel } { $version } wurde heruntergeladen und kann gestartet werden
+
+## Beschriftungen der Oberfläche
+
+sidebar-launch = Starten
+sidebar-profiles = Profile
+sidebar-settings = Einstellungen
+sidebar-about = Über
+sidebar-log-out = Abmelden
+interface-title = Oberfläche
+interface-language = Sprache
+interface-language-system = Systemsprache
+notification-copy = Kopieren
+settings-title = Einstellungen
+settings-description = Konfiguriere alle Eigenschaften, mit denen dein Spiel gestartet wird
+settings-open-directory = Launcher-Verzeichnis öffnen
+settings-save = Änderungen speichern
+settings-rejected = Deine Änderungen an { $file } wurden ignoriert: { $reason }
+game-title = Spieleinstellungen
+game-description = Alle Einstellungen rund um Minecraft und das Spiel
+game-memory = Zugewiesener Arbeitsspeicher (im Spiel, empfohlen: { $recommended } MB)
+game-directory = Spielverzeichnis (wird nach einem Neustart übernommen)
+game-directory-detected = Automatisch erkannt
+game-directory-browse = Durchsuchen
+game-minimum-memory = Minimaler Arbeitsspeicher (MB)
+game-minimum-memory-default = Von Java gewählt
+game-jvm-preset = JVM-Voreinstellung
+game-jvm-preset-none = Keine
+game-jvm-preset-g1 = G1 (optimiert)
+game-jvm-preset-zgc = ZGC
+game-window-width = Fensterbreite
+game-window-height = Fensterhöhe
+game-window-default = Standard
+game-fullscreen = Vollbild
+game-jvm-arguments = JVM-Argumente
+game-wrapper = Wrapper-Befehl
+game-environment = Umgebungsvariablen (ein SCHLÜSSEL=WERT pro Zeile)
+game-pre-launch = Befehl vor dem Start
+game-post-exit = Befehl nach dem Beenden
+game-saved = Deine Spieleinstellungen wurden gespeichert
+wine-title = Wine
+wine-description = Führt Programme, die es nur für Windows gibt, unter Linux und macOS aus
+wine-enabled = Aktiviert
+wine-runner = Runner
+wine-binary = Programm
+wine-prefix = Präfix
+wine-prefix-default = Im Launcher-Verzeichnis
+wine-dxvk = DXVK
+wine-esync = Esync
+wine-initialize = Präfix initialisieren
+wine-saved = Deine Wine-Einstellungen wurden gespeichert
+wine-prefix-ready = Das Präfix unter { $prefix } ist bereit
+updates-title = Updates
+updates-description = Sucht nach neuen Builds aller Kanäle, während der Launcher geöffnet ist
+updates-poll-interval = Prüfen alle (Minuten)
+updates-retained-versions = Behaltene Versionen pro Kanal
+updates-pre-download = Neue Builds sofort herunterladen
+updates-check = Jetzt prüfen
+updates-saved = Deine Update-Einstellungen wurden gespeichert
+updates-checked = Alle Kanäle wurden nach neuen Builds durchsucht
+usage-title = Speicher
+usage-description = Speicherplatz, den der Launcher und dein Spiel belegen
+usage-category-versions = Versionen
+usage-category-logs = Logs
+usage-category-caches = Caches
+usage-category-libraries = Bibliotheken
+usage-category-assets = Assets
+usage-category-runtimes = Laufzeitumgebungen
+usage-clean = Leeren
+usage-cleaned = { $size } an { $category } freigegeben
+usage-old-versions = Behält die neuesten Versionen jedes Kanals und die festgelegten
+usage-remove-old-versions = Alte Versionen entfernen
+usage-removed-old-versions = { $count } alte Version(en) entfernt, { $size } freigegeben
+transfer-title = Übertragen
+transfer-description = Teile deine Einstellungen oder setze sie auf ihre Standardwerte zurück
+transfer-file-type = Moon-Einstellungen
+transfer-export = Einstellungen exportieren
+transfer-import = Einstellungen importieren
+transfer-unchanged = Die Datei ändert keine deiner Einstellungen
+transfer-apply = Übernehmen
+transfer-cancel = Abbrechen
+transfer-reset = Zurücksetzen
+transfer-exported = Deine Einstellungen wurden exportiert
+transfer-imported = Die Einstellungen wurden importiert
+transfer-reset-done = Die Einstellungen wurden zurückgesetzt
+section-game = Spiel
+section-versions = Versionen
+section-updates = Updates
+section-login = Anmeldung
+section-interface = Oberfläche
+directory-launcher = Launcher-Verzeichnis
+rollback-title = Zurücksetzen
+rollback-button = Zurücksetzen
+rollback-done = { $channel } verwendet jetzt { $version }
+hwid-mismatch-title = HWID stimmt nicht überein
+hwid-mismatch-description = Verglichen mit deiner letzten erfolgreichen Anmeldung auf diesem Gerät
+hwid-status-unchanged = unverändert
+hwid-status-changed = geändert
+hwid-status-added = neu lesbar
+hwid-status-removed = nicht mehr lesbar
+hwid-status-unavailable = nicht lesbar
+hwid-status-unrecorded = keine vorherige Anmeldung gespeichert
+hwid-reset-title = HWID-Zurücksetzung
+hwid-reset-status = Deine Anfrage zur HWID-Zurücksetzung ist { $status }
+hwid-reset-pending = ausstehend
+hwid-reset-approved = genehmigt
+hwid-reset-denied = abgelehnt
+hwid-reset-request = HWID-Zurücksetzung anfordern
+hwid-reset-requested = Deine HWID-Zurücksetzung wurde angefordert
+serial-failed-title = Hardwarefehler
+serial-failed = Die Seriennummer deines Geräts konnte nicht geladen werden
+serial-failed-unknown = Die Seriennummer des Geräts konnte nicht abgerufen werden, bitte eröffne ein Issue auf GitHub
+serial-offline = Du kannst weiterhin Einstellungen ändern und installierte Versionen offline spielen, zum Anmelden wird die Seriennummer aber benötigt
+serial-retry = Erneut versuchen
+serial-continue = Weiter
@@ -0,0 +1,290 @@
+# English messages of the launcher, every other catalog falls back to this one
+
+## Login and the servers of the launcher
+
+server-unreachable = Failed to reach the servers, please check your connection
+server-internal-error = Internal server error, please create a ticket
+server-rate-limited = You are currently rate-limited, please wait one minute
+unknown-error = An unknown error occurred, please create an issue on GitHub
+auth-invalid-request = Invalid login request received, please open a GitHub issue
+auth-invalid-response = Received an invalid login response, please open a GitHub issue
+auth-invalid-account = Please make sure you set both a HWID and a username through the discord bot
+auth-hwid-mismatch = Your HWID does not match, please check what changed and request a HWID reset
+auth-no-user = No user with the UID you entered could be found, please make sure you entered your UID correctly
+auth-serial-unavailable = The serial of your device could not be loaded, please restart the launcher to try again
+hwid-invalid-request = Invalid HWID reset request received, please open a GitHub issue
+hwid-invalid-response = Received an invalid HWID reset response, please open a GitHub issue
+hwid-already-requested = You already requested a HWID reset, please wait until it has been reviewed
+hwid-no-request = You haven't requested a HWID reset yet
+
+## Downloads and installations
+
+download-unreachable = Failed to reach the download servers, please check your connection
+download-invalid-response = Received an invalid download response, please open a GitHub issue
+download-invalid-session = Your session expired, please restart the launcher
+download-insufficient-permissions = You don't have enough permissions to download this channel
+install-download-failed = Failed to download the version, please check your connection
+install-unexpected-status = The download server returned an unexpected status code, please open a GitHub issue
+install-insufficient-space = There is not enough disk space available to install this version
+install-create-directory = Failed to create the version directory
+install-write-file = Failed to write the version file
+install-move-file = Failed to move the version file into place
+install-record = Failed to record the installation
+retention-record = Failed to record the removed installations
+rollback-no-previous-version = There is no previously installed version to roll back to
+rollback-selection-save = Failed to save the version selection of this channel
+usage-remove-directory = Failed to remove the directory, make sure the game is closed
+usage-shared-category = These files might be used by other launchers and cannot be cleaned
+
+## Launching the game
+
+launch-unknown-channel = You don't have access to this channel, please restart the launcher
+launch-no-version = This channel has no versions available yet
+launch-not-installed = No version of this channel is installed, please sign in to download one
+launch-game-directory = The game directory could not be created, please check its permissions
+launch-pre-launch-spawn = Failed to run the pre-launch command
+launch-pre-launch-failed = The pre-launch command failed, the game has not been started
+launch-process-spawn = Failed to start the game, please make sure Java and the wrapper are installed
+launch-insufficient-memory = This channel needs at least { $minimum } MB of memory, but only { $memory } MB are allocated
+memory-reserved-warning = This leaves less than 2 GB of memory for your operating system, which might slow down your whole computer
+memory-available-warning = This is more memory than currently available, close other programs before launching
+
+## Profiles and directories
+
+profile-unknown = This profile doesn't exist anymore
+profile-copy-directory = Failed to copy the instance directory, please check your disk space
+profile-remove-directory = Failed to remove the instance directory, make sure the game is closed
+profile-invalid-directory = The instance directory of this profile is outside of the instances directory and has been kept
+directory-missing = This directory doesn't exist yet, it is created once it is needed
+directory-open = Unable to open the directory in your file explorer
+
+## Wine
+
+wine-unsupported = Windows programs can be started directly on Windows
+wine-disabled = Please enable Wine in the settings to run Windows programs
+wine-prefix-creation = The wine prefix could not be created, please check its permissions
+wine-runner-spawn = Failed to start the runner, please check the configured binary
+wine-prefix-init = The runner failed to initialise the wine prefix
+
+## The proprietary library and the serial of the device
+
+proprietary-different-library = The manifest describes a different proprietary library, please retry to download it again
+proprietary-hash-mismatch = The proprietary library does not match its signed hash, please retry to download it again
+proprietary-read = The proprietary library could not be read, please retry to download it again
+proprietary-invalid-signature = The manifest of the proprietary library is not signed correctly, please retry to download it again
+proprietary-damaged = The proprietary library is outdated or damaged, please retry to download it again
+proprietary-invalid-serial = The proprietary library returned an invalid serial, please open an issue on GitHub
+proprietary-serial-os = The serial of your device could not be read from the operating system, please open an issue on GitHub
+proprietary-serial-rejected = The proprietary library rejected the serial request, please open an issue on GitHub
+proprietary-remove-old = The old proprietary library could not be removed, please check the permissions of the launcher directory
+proprietary-load = The proprietary library could not be loaded, please open an issue on GitHub
+proprietary-abi-mismatch = The proprietary library does not match this launcher version, please update the launcher
+proprietary-manifest-missing = The manifest of the proprietary library is missing
+proprietary-manifest-read = The manifest of the proprietary library could not be read, please open an issue on GitHub
+proprietary-download-failed = Failed to download the proprietary library, please check your connection
+proprietary-unexpected-status = The download server returned an unexpected status code for the proprietary library, please open an issue on GitHub
+proprietary-write = Failed to write the proprietary library, please check the permissions of the launcher directory
+proprietary-move = Failed to move the proprietary library into place, please check the permissions of the launcher directory
+serial-read = The serial of your device could not be read, please open an issue on GitHub
+serial-linux-only = The linux serial provider only works on Linux
+serial-unavailable = The machine id could not be read
+
+## Settings
+
+bundle-read = Failed to read the settings bundle
+bundle-invalid = The file is not a valid settings bundle
+bundle-newer-version = This settings bundle was created by a newer launcher, please update
+bundle-serialize = Failed to serialize the settings bundle
+bundle-write = Failed to write the settings bundle
+settings-reset-installations = Installations can only be reset by cleaning the versions storage
+settings-reset-hardware = The hardware fingerprint is not a setting and cannot be reset
+settings-invalid-uid = Please enter a valid UID
+settings-invalid-minimum-memory = The minimum memory has to be between 1 MB and the allocated memory
+settings-relative-game-directory = The game directory has to be an absolute path
+settings-invalid-window-size = The window size has to be larger than zero
+settings-incomplete-window-size = Please set both the window width and height
+settings-empty-wrapper-argument = The wrapper command contains an empty argument
+settings-empty-hook = Hooks can't be empty, remove them instead
+settings-invalid-environment = Environment variable names can't be empty or contain '='
+settings-too-little-memory = The game requires at least 512 MB of memory
+settings-too-much-memory = This is more memory than your computer has
+settings-channel-memory = This channel needs more memory than the allocated memory
+settings-empty-jvm-argument = The JVM arguments contain an empty argument
+settings-jvm-maximum-memory = Please use the memory setting instead of -Xmx
+settings-jvm-minimum-memory = Please use the minimum memory setting instead of -Xms
+settings-jvm-garbage-collector = The garbage collector is already chosen by the JVM preset
+settings-selection-channel = Every version selection requires a channel
+settings-duplicate-selection = A channel can only have one version selection
+settings-update-interval = Updates can be checked at most every 5 minutes
+settings-keep-versions = At least one installed version has to be kept
+settings-proton-script = Please choose the proton script to run Windows programs with
+settings-relative-runner = The runner binary has to be an absolute path
+settings-relative-prefix = The wine prefix has to be an absolute path
+settings-invalid-locale = Please choose a valid language
+settings-profile-id = Every profile requires a unique id which is a valid folder name
+settings-profile-name = Please enter a name for the profile
+settings-profile-channel = Every profile requires a channel
+settings-restored-backup = { $file } was broken, the last saved version has been restored
+settings-load-failed = { $file } could not be loaded, its default settings are used until you change them
+
+## Storage
+
+storage-serialize = Failed to serialize given storage data
+storage-deserialize = Failed to deserialize file content of storage type
+storage-invalid-object = The storage file does not contain a valid JSON object
+storage-create-temporary = Failed to create the temporary storage file
+storage-write-temporary = Failed to write the temporary storage file
+storage-back-up = Failed to back up the previous storage file
+storage-replace = Failed to replace the storage file
+storage-open = Failed to open the storage file
+location-working-directory = The launcher directory could not be created, please check its permissions
+location-home-missing = Your home directory could not be found
+
+## Command line
+
+cli-not-logged-in = You haven't logged in yet, please run the login command first
+cli-no-channel-access = You don't have access to this channel
+cli-settings-serialize = The settings could not be serialized, please open an issue on GitHub
+cli-unknown-setting = There is no setting with this name
+cli-setting-name = Settings are changed by their full name like game.memory
+cli-unknown-section = There is no settings section with this name
+cli-invalid-value = The value does not fit this setting
+cli-error = error: { $message }
+cli-warning = warning: { $message }
+cli-logged-in = Logged in as { $username }, channels available: { $channels }
+cli-latest = { " " }(latest)
+cli-installed = Installed { $channel } { $version } to { $path }
+cli-launched = Launched { $channel } { $version }
+cli-file-ok = ok	{ $file }
+cli-file-broken = broken	{ $file }
+
+## Details of errors
+
+detail-http-status = HTTP status { $status }
+detail-error-code = Error code { $code }
+detail-abi-version = Expected ABI version { $expected }, found { $found }
+detail-invalid-setting = Invalid setting: { $field }
+detail-disk-space = { $required } bytes are required, { $available } bytes are available
+
+## Versions and updates
+
+version-pinned-unavailable = Your pinned version { $pinned } is no longer available, { $latest } will be launched instead
+version-pinned-not-installed = Your pinned version { $pinned } is not installed, { $installed } will be launched instead
+version-missing = The installed version is missing
+update-title = Update available
+update-available = { $channel } { $version } is now available
+update-downloaded = { $channel } { $version } has been downloaded and is ready to launch
+
+## Labels of the gui
+
+sidebar-launch = Launch
+sidebar-profiles = Profiles
+sidebar-settings = Settings
+sidebar-about = About
+sidebar-log-out = Log Out
+interface-title = Interface
+interface-language = Language
+interface-language-system = System language
+notification-copy = Copy
+settings-title = Settings
+settings-description = Configure all types of properties which are used to launch your game
+settings-open-directory = Open Launcher Directory
+settings-save = Save changes
+settings-rejected = Your changes to { $file } have been ignored: { $reason }
+game-title = Game settings
+game-description = All minecraft / game related settings
+game-memory = Allocated memory (in-game, recommended: { $recommended } MB)
+game-directory = Game directory (applied after a restart)
+game-directory-detected = Detected automatically
+game-directory-browse = Browse
+game-minimum-memory = Minimum memory (MB)
+game-minimum-memory-default = Chosen by Java
+game-jvm-preset = JVM preset
+game-jvm-preset-none = None
+game-jvm-preset-g1 = G1 (tuned)
+game-jvm-preset-zgc = ZGC
+game-window-width = Window width
+game-window-height = Window height
+game-window-default = Default
+game-fullscreen = Fullscreen
+game-jvm-arguments = JVM arguments
+game-wrapper = Wrapper command
+game-environment = Environment variables (one KEY=VALUE per line)
+game-pre-launch = Pre-launch command
+game-post-exit = Post-exit command
+game-saved = Successfully saved your game settings
+wine-title = Wine
+wine-description = Runs Windows-only programs on Linux and macOS
+wine-enabled = Enabled
+wine-runner = Runner
+wine-binary = Binary
+wine-prefix = Prefix
+wine-prefix-default = Inside the launcher directory
+wine-dxvk = DXVK
+wine-esync = Esync
+wine-initialize = Initialise prefix
+wine-saved = Successfully saved your wine settings
+wine-prefix-ready = The prefix at { $prefix } is ready
+updates-title = Updates
+updates-description = Looks for new builds of every channel while the launcher is open
+updates-poll-interval = Check every (minutes)
+updates-retained-versions = Versions kept per channel
+updates-pre-download = Download new builds right away
+updates-check = Check now
+updates-saved = Successfully saved your update settings
+updates-checked = Checked all channels for new builds
+usage-title = Storage
+usage-description = Disk space used by the launcher and your game
+usage-category-versions = Versions
+usage-category-logs = Logs
+usage-category-caches = Caches
+usage-category-libraries = Libraries
+usage-category-assets = Assets
+usage-category-runtimes = Runtimes
+usage-clean = Clean
+usage-cleaned = Freed { $size } of { $category }
+usage-old-versions = Keeps the newest versions of every channel and the pinned ones
+usage-remove-old-versions = Remove old versions
+usage-removed-old-versions = Removed { $count } old version(s), freed { $size }
+transfer-title = Transfer
+transfer-description = Share your settings or reset them to their defaults
+transfer-file-type = Moon settings
+transfer-export = Export settings
+transfer-import = Import settings
+transfer-unchanged = The file doesn't change any of your settings
+transfer-apply = Apply
+transfer-cancel = Cancel
+transfer-reset = Reset
+transfer-exported = Successfully exported your settings
+transfer-imported = Successfully imported the settings
+transfer-reset-done = Successfully reset the settings
+section-game = Game
+section-versions = Versions
+section-updates = Updates
+section-login = Login
+section-interface = Interface
+directory-launcher = Launcher directory
+rollback-title = Rollback
+rollback-button = Roll back
+rollback-done = { $channel } now uses { $version }
+hwid-mismatch-title = HWID mismatch
+hwid-mismatch-description = Compared to your last successful login on this device
+hwid-status-unchanged = unchanged
+hwid-status-changed = changed
+hwid-status-added = newly readable
+hwid-status-removed = no longer readable
+hwid-status-unavailable = not readable
+hwid-status-unrecorded = no previous login recorded
+hwid-reset-title = HWID Reset
+hwid-reset-status = Your HWID reset request is { $status }
+hwid-reset-pending = pending
+hwid-reset-approved = approved
+hwid-reset-denied = denied
+hwid-reset-request = Request HWID reset
+hwid-reset-requested = Your HWID reset has been requested
+serial-failed-title = Hardware Error
+serial-failed = The serial of your device could not be loaded
+serial-failed-unknown = Device serial fetching has failed, please open an issue on GitHub
+serial-offline = You can still change settings and play installed versions offline, signing in needs the serial though
+serial-retry = Retry
+serial-continue = Continue
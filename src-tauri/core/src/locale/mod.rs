@@ -0,0 +1,203 @@
+//! Translations of every message shown to the user
+//!
+//! Errors and other messages only carry the key of a message like `auth-hwid-mismatch` and its
+//! arguments, the text is looked up in the Fluent catalogs of this module once it is displayed.
+//! A language is added by writing another catalog and listing it in [CATALOGS], the catalogs are
+//! compiled into the launcher so it never shows a key because a file is missing
+
+use std::collections::BTreeMap;
+use std::sync::{PoisonError, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::resolver::errors::{ReferenceKind, ResolverError};
+use fluent_bundle::{FluentArgs, FluentError, FluentResource};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use fluent_syntax::ast::Entry;
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use unic_langid::LanguageIdentifier;
+
+use crate::storage::types::InterfaceSettingData;
+
+/// The locale every message exists in, used if no other catalog contains a message
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// The id, the name of the language in itself and the catalog of every supported locale
+const CATALOGS: [(&str, &str, &str); 2] = [
+    ("en", "English", include_str!("en.ftl")),
+    ("de", "Deutsch", include_str!("de.ftl")),
+];
+
+/// A parsed catalog of [CATALOGS]
+struct Catalog {
+    id: LanguageIdentifier,
+    bundle: FluentBundle<FluentResource>,
+    /// The keys of all messages, which can't be listed through the bundle itself
+    keys: Vec<String>,
+}
+
+static LOADED_CATALOGS: OnceCell<Vec<Catalog>> = OnceCell::new();
+
+/// The indices of the catalogs messages are looked up in, empty until a locale has been applied
+static ACTIVE_CHAIN: RwLock<Vec<usize>> = RwLock::new(Vec::new());
+
+/// A locale the user can choose
+#[derive(Clone, Debug, Serialize)]
+pub struct LocaleInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+}
+
+/// Returns all locales which have a catalog
+pub fn locales() -> Vec<LocaleInfo> {
+    CATALOGS
+        .iter()
+        .map(|(id, name, _)| LocaleInfo { id, name })
+        .collect()
+}
+
+/// Parses a locale like `de-DE`, the underscores used by most operating systems are accepted
+pub fn parse_locale(locale: &str) -> Option<LanguageIdentifier> {
+    locale.replace('_', "-").parse().ok()
+}
+
+/// Uses the locale chosen inside the interface settings, or the one of the system if there is none
+pub fn apply_settings(settings: &InterfaceSettingData) {
+    let chain = fallback_chain(settings.locale.as_deref());
+    *ACTIVE_CHAIN.write().unwrap_or_else(PoisonError::into_inner) = chain;
+}
+
+/// Returns the locale messages are currently looked up in first
+pub fn active_locale() -> String {
+    catalogs()[active_chain()[0]].id.to_string()
+}
+
+/// Translates a message into the active locale
+///
+/// Falls back to the next locale of the chain if a catalog is missing the message, the key itself
+/// is returned if no catalog contains it
+pub fn translate(key: &str, arguments: &[(&str, String)]) -> String {
+    lookup(key, arguments).unwrap_or_else(|| key.to_string())
+}
+
+/// Returns every message of the active locale
+///
+/// Used by the gui to translate its own labels, messages missing in the active locale are taken
+/// from the next locale of the chain. Arguments are kept as placeholders like `{$channel}` which
+/// the gui substitutes itself
+pub fn messages() -> BTreeMap<String, String> {
+    let catalogs = catalogs();
+    let mut messages = BTreeMap::new();
+    // Later locales of the chain are overwritten by the earlier ones
+    for index in active_chain().into_iter().rev() {
+        let catalog = &catalogs[index];
+        for key in &catalog.keys {
+            let pattern = catalog
+                .bundle
+                .get_message(key)
+                .and_then(|message| message.value());
+            if let Some(pattern) = pattern {
+                let mut errors = Vec::new();
+                let value = catalog.bundle.format_pattern(pattern, None, &mut errors);
+                if errors.iter().all(is_missing_argument) {
+                    messages.insert(key.clone(), value.into_owned());
+                }
+            }
+        }
+    }
+    messages
+}
+
+/// Whether an error only means that an argument has not been passed, which leaves its placeholder
+fn is_missing_argument(error: &FluentError) -> bool {
+    matches!(
+        error,
+        FluentError::ResolverError(ResolverError::Reference(ReferenceKind::Variable { .. }))
+    )
+}
+
+/// Orders the catalogs by how well they fit the chosen locale, the locale of the system and
+/// finally the [FALLBACK_LOCALE]
+///
+/// A region is only a preference, `de-AT` uses the `de` catalog if there is no `de-AT` one
+fn fallback_chain(chosen: Option<&str>) -> Vec<usize> {
+    let available: Vec<LanguageIdentifier> = catalogs()
+        .iter()
+        .map(|catalog| catalog.id.clone())
+        .collect();
+    let fallback = parse_locale(FALLBACK_LOCALE).unwrap_or_default();
+    let requested: Vec<LanguageIdentifier> = chosen
+        .map(str::to_string)
+        .into_iter()
+        .chain(sys_locale::get_locale())
+        .filter_map(|locale| parse_locale(&locale))
+        .collect();
+
+    negotiate_languages(
+        &requested,
+        &available,
+        Some(&fallback),
+        NegotiationStrategy::Filtering,
+    )
+    .into_iter()
+    .filter_map(|locale| available.iter().position(|id| id == locale))
+    .collect()
+}
+
+fn active_chain() -> Vec<usize> {
+    let chain = ACTIVE_CHAIN
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    if chain.is_empty() {
+        fallback_chain(None)
+    } else {
+        chain
+    }
+}
+
+fn lookup(key: &str, arguments: &[(&str, String)]) -> Option<String> {
+    let mut fluent_arguments = FluentArgs::new();
+    for (name, value) in arguments {
+        fluent_arguments.set(*name, value.clone());
+    }
+
+    let catalogs = catalogs();
+    active_chain().into_iter().find_map(|index| {
+        let bundle = &catalogs[index].bundle;
+        let pattern = bundle.get_message(key)?.value()?;
+        // Missing arguments are rendered as their name, which is still better than nothing
+        let mut errors = Vec::new();
+        Some(
+            bundle
+                .format_pattern(pattern, Some(&fluent_arguments), &mut errors)
+                .into_owned(),
+        )
+    })
+}
+
+fn catalogs() -> &'static Vec<Catalog> {
+    LOADED_CATALOGS.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(id, _, source)| {
+                let id = parse_locale(id).unwrap_or_default();
+                // A catalog with syntax errors still contains every message before the error
+                let resource = FluentResource::try_new(source.to_string())
+                    .unwrap_or_else(|(resource, _)| resource);
+                let keys = resource
+                    .entries()
+                    .filter_map(|entry| match entry {
+                        Entry::Message(message) => Some(message.id.name.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                let mut bundle = FluentBundle::new_concurrent(vec![id.clone()]);
+                // The isolation marks would show up as garbage inside terminals and notifications
+                bundle.set_use_isolating(false);
+                let _ = bundle.add_resource(resource);
+                Catalog { id, bundle, keys }
+            })
+            .collect()
+    })
+}
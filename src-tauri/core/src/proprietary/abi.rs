@@ -0,0 +1,32 @@
+//! The C ABI between the launcher and the proprietary library
+//!
+//! Only plain integers and pointers cross the boundary, so the library and the launcher may
+//! be built with different compilers. This file is shared with the stub library in
+//! `proprietary-stub`, which implements the same ABI for development
+
+/// The ABI version this launcher speaks, libraries reporting another one are refused
+pub const SERIAL_ABI_VERSION: u32 = 1;
+
+/// `extern "C" fn() -> u32`, returns the ABI version the library implements
+pub const ABI_VERSION_SYMBOL: &[u8] = b"moon_serial_abi_version\0";
+
+/// See [FetchSerialFn]
+pub const FETCH_SERIAL_SYMBOL: &[u8] = b"moon_fetch_serial\0";
+
+/// The serial has been written to the buffer
+pub const SERIAL_OK: i32 = 0;
+/// The buffer is too small, `length` contains the required capacity
+pub const SERIAL_BUFFER_TOO_SMALL: i32 = 1;
+/// The operating system did not provide the information the serial is made of
+pub const SERIAL_OS_ERROR: i32 = 2;
+/// A null pointer has been passed to the library
+pub const SERIAL_INVALID_ARGUMENT: i32 = 3;
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+
+/// Writes the serial as UTF-8 without a terminating null byte into the caller-provided
+/// `buffer` of `capacity` bytes and returns one of the `SERIAL_*` codes
+///
+/// `length` receives the length of the serial in bytes, even if the buffer is too small
+pub type FetchSerialFn =
+    unsafe extern "C" fn(buffer: *mut u8, capacity: usize, length: *mut usize) -> i32;
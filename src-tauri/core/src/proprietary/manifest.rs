@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::proprietary::ProprietaryError;
+
+/// The hex encoded public key all library manifests are signed with
+///
+/// The key is handed to the build by the release pipeline, which also keeps the private key
+/// and signs the manifests on the CDN. Builds without it refuse every downloaded library
+pub const MANIFEST_PUBLIC_KEY: Option<&str> = option_env!("MOON_MANIFEST_PUBLIC_KEY");
+
+/// Describes a single build of the proprietary library
+///
+/// The manifest is stored next to the library and verified again before every load, so a
+/// library which has been changed on disk is never loaded
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LibraryManifest {
+    /// The file name of the library, which differs between the operating systems
+    pub file: String,
+    pub version: String,
+    /// The hex encoded SHA-256 hash of the library
+    pub sha256: String,
+    /// The hex encoded ed25519 signature of [LibraryManifest::signed_content]
+    pub signature: String,
+}
+
+impl LibraryManifest {
+    /// The content covered by the signature, every field is included so none of them can be
+    /// swapped with the one of another build
+    pub fn signed_content(&self) -> String {
+        format!("{}\n{}\n{}", self.file, self.version, self.sha256)
+    }
+
+    /// Checks that the manifest is signed and describes the library this launcher expects
+    pub fn verify(&self, file: &str, version: &str) -> Result<(), ProprietaryError> {
+        let key = MANIFEST_PUBLIC_KEY
+            .and_then(|key| hex::decode(key).ok())
+            .and_then(|key| <[u8; 32]>::try_from(key).ok())
+            .and_then(|key| VerifyingKey::from_bytes(&key).ok())
+            .ok_or_else(invalid_signature)?;
+        let signature = hex::decode(&self.signature)
+            .ok()
+            .and_then(|signature| Signature::from_slice(&signature).ok())
+            .ok_or_else(invalid_signature)?;
+        key.verify_strict(self.signed_content().as_bytes(), &signature)
+            .map_err(|_| invalid_signature())?;
+
+        if self.file != file || self.version != version {
+            return Err(ProprietaryError::InvalidManifest {
+                message: "proprietary-different-library",
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the content matches the hash of the manifest
+    pub fn verify_content(&self, content: &[u8]) -> Result<(), ProprietaryError> {
+        if !hex::encode(Sha256::digest(content)).eq_ignore_ascii_case(&self.sha256) {
+            return Err(ProprietaryError::IntegrityCheckFailed {
+                message: "proprietary-hash-mismatch",
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads a manifest stored next to the library, a missing or damaged one is simply
+    /// downloaded again
+    pub fn read(path: &Path) -> Option<LibraryManifest> {
+        let content = std::fs::read(path).ok()?;
+        serde_json::from_slice(&content).ok()
+    }
+}
+
+fn invalid_signature() -> ProprietaryError {
+    ProprietaryError::InvalidManifest {
+        message: "proprietary-invalid-signature",
+    }
+}
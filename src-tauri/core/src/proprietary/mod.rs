@@ -0,0 +1,463 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+use libloading::{Library, Symbol};
+use once_cell::sync::OnceCell;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::proprietary::abi::{
+    AbiVersionFn, FetchSerialFn, ABI_VERSION_SYMBOL, FETCH_SERIAL_SYMBOL, SERIAL_ABI_VERSION,
+    SERIAL_BUFFER_TOO_SMALL, SERIAL_INVALID_ARGUMENT, SERIAL_OK, SERIAL_OS_ERROR,
+};
+use crate::proprietary::manifest::LibraryManifest;
+use crate::storage::location::LauncherPaths;
+
+pub mod abi;
+pub mod manifest;
+
+pub const PROPRIETARY_LIBRARY_VERSION: &str = "launcher_lib-2.0";
+pub const BASE_DOWNLOAD_URL: &str = "https://cdn.moonclient.xyz/launcher/proprietary/";
+
+/// Every file of any library version starts with this, which is how stale versions are found
+const PROPRIETARY_LIBRARY_PREFIX: &str = "launcher_lib-";
+
+/// Development builds load the library at this path instead, without downloading or verifying
+/// it, which is how the stub library in `proprietary-stub` is used
+pub const LIBRARY_OVERRIDE_VARIABLE: &str = "MOON_PROPRIETARY_LIBRARY";
+
+/// The capacity of the buffer the serial is first written to, larger serials are fetched again
+/// with the capacity requested by the library
+const SERIAL_BUFFER_CAPACITY: usize = 256;
+
+/// The proprietary library once it has been loaded successfully
+///
+/// A failed load is not remembered, so every call after a failure tries again
+static PROPRIETARY_LIBRARY: OnceCell<Library> = OnceCell::new();
+
+/// The lock of the copy this process loads the library from, held for as long as it runs so
+/// other processes never remove the copy, see [remove_stale_libraries]
+static LOADED_LIBRARY_LOCK: OnceCell<File> = OnceCell::new();
+
+/// Contains all errors which might happen on loading the proprietary library or fetching the
+/// serial through it
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ProprietaryError {
+    Location {
+        message: &'static str,
+    },
+    DownloadFailed {
+        message: &'static str,
+    },
+    UnexpectedStatus {
+        message: &'static str,
+        status: u16,
+    },
+    FileWrite {
+        message: &'static str,
+        path: PathBuf,
+    },
+    LoadFailed {
+        message: &'static str,
+        path: PathBuf,
+    },
+    MissingSymbol {
+        message: &'static str,
+    },
+    SerialUnavailable {
+        message: &'static str,
+        code: i32,
+    },
+    AbiMismatch {
+        message: &'static str,
+        expected: u32,
+        found: u32,
+    },
+    InvalidManifest {
+        message: &'static str,
+    },
+    IntegrityCheckFailed {
+        message: &'static str,
+    },
+}
+
+impl ProprietaryError {
+    /// Returns the key of the message describing the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            ProprietaryError::Location { message }
+            | ProprietaryError::DownloadFailed { message }
+            | ProprietaryError::UnexpectedStatus { message, .. }
+            | ProprietaryError::FileWrite { message, .. }
+            | ProprietaryError::LoadFailed { message, .. }
+            | ProprietaryError::MissingSymbol { message }
+            | ProprietaryError::SerialUnavailable { message, .. }
+            | ProprietaryError::AbiMismatch { message, .. }
+            | ProprietaryError::InvalidManifest { message }
+            | ProprietaryError::IntegrityCheckFailed { message } => message,
+        }
+    }
+}
+
+/// Returns whether the proprietary library has already been loaded
+pub fn is_loaded() -> bool {
+    PROPRIETARY_LIBRARY.get().is_some()
+}
+
+/// Fetches the serial of the current device, loading the proprietary library first if
+/// necessary
+pub async fn fetch_serial(paths: &LauncherPaths) -> Result<String, ProprietaryError> {
+    let library = load_proprietary_library(paths).await?;
+
+    // SAFETY: The symbol is declared with the signature of the ABI, whose version has been
+    // checked on load. The library lives until the launcher exits
+    unsafe {
+        let function: Symbol<FetchSerialFn> =
+            library
+                .get(FETCH_SERIAL_SYMBOL)
+                .map_err(|_| ProprietaryError::MissingSymbol {
+                    message: "proprietary-damaged",
+                })?;
+
+        let mut buffer = vec![0u8; SERIAL_BUFFER_CAPACITY];
+        let mut length = 0;
+        let mut code = function(buffer.as_mut_ptr(), buffer.len(), &mut length);
+        if code == SERIAL_BUFFER_TOO_SMALL {
+            buffer.resize(length, 0);
+            code = function(buffer.as_mut_ptr(), buffer.len(), &mut length);
+        }
+
+        if code != SERIAL_OK {
+            return Err(ProprietaryError::SerialUnavailable {
+                message: serial_error_message(code),
+                code,
+            });
+        }
+
+        buffer.truncate(length.min(buffer.len()));
+        String::from_utf8(buffer).map_err(|_| ProprietaryError::SerialUnavailable {
+            message: "proprietary-invalid-serial",
+            code,
+        })
+    }
+}
+
+/// Describes an error code returned by the serial function
+fn serial_error_message(code: i32) -> &'static str {
+    match code {
+        SERIAL_OS_ERROR => "proprietary-serial-os",
+        SERIAL_BUFFER_TOO_SMALL | SERIAL_INVALID_ARGUMENT => "proprietary-serial-rejected",
+        _ => "serial-read",
+    }
+}
+
+/// Removes the downloaded library and its manifest so the next load downloads them again
+///
+/// Does nothing once the library has been loaded, it cannot be replaced while in use
+pub fn discard_downloaded_library(paths: &LauncherPaths) -> Result<(), ProprietaryError> {
+    if is_loaded() {
+        return Ok(());
+    }
+
+    let working_directory = working_directory(paths)?;
+    for path in [
+        working_directory.join(library_name()),
+        working_directory.join(manifest_name()),
+    ] {
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(_) => {
+                return Err(ProprietaryError::FileWrite {
+                    message: "proprietary-remove-old",
+                    path,
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Loads the proprietary library of the launcher
+///
+/// The library is only loaded once it matches the hash of its signed manifest. If either of
+/// them is missing, damaged or belongs to another version both are downloaded again
+async fn load_proprietary_library(
+    paths: &LauncherPaths,
+) -> Result<&'static Library, ProprietaryError> {
+    if let Some(library) = PROPRIETARY_LIBRARY.get() {
+        return Ok(library);
+    }
+
+    if cfg!(debug_assertions) {
+        if let Some(path) = std::env::var_os(LIBRARY_OVERRIDE_VARIABLE) {
+            return PROPRIETARY_LIBRARY.get_or_try_init(|| open_library(Path::new(&path)));
+        }
+    }
+
+    let working_directory = working_directory(paths)?;
+    let library_path = working_directory.join(library_name());
+    let manifest_path = working_directory.join(manifest_name());
+
+    let loaded_path = match verified_library_copy(&library_path, &manifest_path) {
+        Ok(loaded_path) => loaded_path,
+        Err(_) => {
+            download_proprietary_library(&library_path, &manifest_path).await?;
+            verified_library_copy(&library_path, &manifest_path)?
+        }
+    };
+
+    let library = PROPRIETARY_LIBRARY.get_or_try_init(|| open_library(&loaded_path));
+
+    // Loaded libraries stay mapped without their file, except on Windows where the copy can
+    // only be removed as a stale library on the next start
+    let _ = std::fs::remove_file(&loaded_path);
+    let library = library?;
+
+    // Older versions are never loaded again, failing to remove them only wastes some space
+    remove_stale_libraries(&working_directory);
+    Ok(library)
+}
+
+/// Opens the library and checks that it implements the ABI of this launcher
+fn open_library(path: &Path) -> Result<Library, ProprietaryError> {
+    // Hack the mainframe by loading the library from a specific path (not really a hack, but still)
+    let library = unsafe { Library::new(path) }.map_err(|_| ProprietaryError::LoadFailed {
+        message: "proprietary-load",
+        path: path.to_path_buf(),
+    })?;
+
+    // SAFETY: Every version of the ABI exports this symbol with exactly this signature
+    let found = unsafe {
+        let abi_version: Symbol<AbiVersionFn> =
+            library
+                .get(ABI_VERSION_SYMBOL)
+                .map_err(|_| ProprietaryError::MissingSymbol {
+                    message: "proprietary-damaged",
+                })?;
+        abi_version()
+    };
+
+    if found != SERIAL_ABI_VERSION {
+        return Err(ProprietaryError::AbiMismatch {
+            message: "proprietary-abi-mismatch",
+            expected: SERIAL_ABI_VERSION,
+            found,
+        });
+    }
+    Ok(library)
+}
+
+/// Checks the library on disk against its stored manifest and copies the verified content to a
+/// file only used for a single load
+///
+/// The copy is what gets loaded, so the downloaded library being replaced after it has been
+/// verified can never change what is loaded
+fn verified_library_copy(
+    library_path: &Path,
+    manifest_path: &Path,
+) -> Result<PathBuf, ProprietaryError> {
+    let manifest =
+        LibraryManifest::read(manifest_path).ok_or(ProprietaryError::InvalidManifest {
+            message: "proprietary-manifest-missing",
+        })?;
+    manifest.verify(&library_name(), PROPRIETARY_LIBRARY_VERSION)?;
+
+    let content =
+        std::fs::read(library_path).map_err(|_| ProprietaryError::IntegrityCheckFailed {
+            message: "proprietary-read",
+        })?;
+    manifest.verify_content(&content)?;
+
+    let loaded_path = library_path.with_file_name(loaded_library_name());
+    LOADED_LIBRARY_LOCK.get_or_try_init(|| lock_library_copy(&loaded_path))?;
+    write_private(&loaded_path, &content)?;
+    Ok(loaded_path)
+}
+
+/// Creates and locks the lock file of a library copy
+fn lock_library_copy(loaded_path: &Path) -> Result<File, ProprietaryError> {
+    let lock_path = copy_lock_path(loaded_path);
+    File::create(&lock_path)
+        .and_then(|lock| lock.try_lock_exclusive().map(|_| lock))
+        .map_err(|_| ProprietaryError::FileWrite {
+            message: "proprietary-write",
+            path: lock_path,
+        })
+}
+
+/// The lock file next to a library copy, see [LOADED_LIBRARY_LOCK]
+fn copy_lock_path(loaded_path: &Path) -> PathBuf {
+    let mut lock_path = loaded_path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    PathBuf::from(lock_path)
+}
+
+/// Removes the files of every other library version from the working directory
+///
+/// The copies of other processes are only removed once their lock can be taken, which means
+/// the process has exited. Copies without a lock file are left behind by older launchers
+fn remove_stale_libraries(working_directory: &Path) {
+    let current = [library_name(), manifest_name()];
+    let entries = match std::fs::read_dir(working_directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_library = name
+            .strip_prefix("lib")
+            .unwrap_or(&name)
+            .starts_with(PROPRIETARY_LIBRARY_PREFIX);
+        if !is_library || current.contains(&name) {
+            continue;
+        }
+
+        let path = entry.path();
+        let lock_path = if name.ends_with(".lock") {
+            path.clone()
+        } else {
+            copy_lock_path(&path)
+        };
+        match File::open(&lock_path) {
+            Ok(lock) => {
+                if lock.try_lock_exclusive().is_err() {
+                    continue;
+                }
+                // The lock is released before its file is removed, which Windows requires
+                drop(lock);
+                let _ = std::fs::remove_file(&path);
+                let _ = std::fs::remove_file(&lock_path);
+            }
+            Err(_) => {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+}
+
+/// Resolves the working directory the library is stored in
+fn working_directory(paths: &LauncherPaths) -> Result<PathBuf, ProprietaryError> {
+    paths
+        .create_working_directory()
+        .map(Path::to_path_buf)
+        .map_err(|error| ProprietaryError::Location {
+            message: error.message(),
+        })
+}
+
+/// Choose the library name depending on the os we are running on
+fn library_name() -> String {
+    if cfg!(windows) {
+        format!("{}.dll", PROPRIETARY_LIBRARY_VERSION)
+    } else {
+        format!("lib{}.so", PROPRIETARY_LIBRARY_VERSION)
+    }
+}
+
+/// The name of the copy a library is loaded from, every process uses its own
+fn loaded_library_name() -> String {
+    let process = std::process::id();
+    if cfg!(windows) {
+        format!("{}.{process}.dll", PROPRIETARY_LIBRARY_VERSION)
+    } else {
+        format!("lib{}.{process}.so", PROPRIETARY_LIBRARY_VERSION)
+    }
+}
+
+/// The manifest is named after the library it describes
+fn manifest_name() -> String {
+    format!("{}.manifest.json", library_name())
+}
+
+/// Downloads the signed manifest and the library it describes
+///
+/// Nothing is written before the library has been verified. The library is written to a
+/// partial file first and then moved into place, so an interrupted download is never loaded.
+/// The manifest is moved into place last, a library without a matching manifest is simply
+/// downloaded again
+async fn download_proprietary_library(
+    library_path: &Path,
+    manifest_path: &Path,
+) -> Result<(), ProprietaryError> {
+    let manifest_content = download(&manifest_name()).await?;
+    let manifest: LibraryManifest = serde_json::from_slice(&manifest_content).map_err(|_| {
+        ProprietaryError::InvalidManifest {
+            message: "proprietary-manifest-read",
+        }
+    })?;
+    manifest.verify(&library_name(), PROPRIETARY_LIBRARY_VERSION)?;
+
+    let library_content = download(&library_name()).await?;
+    manifest.verify_content(&library_content)?;
+
+    write_atomically(library_path, &library_content)?;
+    write_atomically(manifest_path, &manifest_content)
+}
+
+/// Downloads a single file of the library
+async fn download(file_name: &str) -> Result<Vec<u8>, ProprietaryError> {
+    let response = Client::new()
+        .get(format!("{BASE_DOWNLOAD_URL}{file_name}"))
+        .send()
+        .await
+        .map_err(|_| ProprietaryError::DownloadFailed {
+            message: "proprietary-download-failed",
+        })?;
+
+    if response.status() != StatusCode::OK {
+        return Err(ProprietaryError::UnexpectedStatus {
+            message: "proprietary-unexpected-status",
+            status: response.status().as_u16(),
+        });
+    }
+
+    let content = response
+        .bytes()
+        .await
+        .map_err(|_| ProprietaryError::DownloadFailed {
+            message: "proprietary-download-failed",
+        })?;
+    Ok(content.to_vec())
+}
+
+/// Writes to a new file only the current user can access, a file left behind by an earlier
+/// attempt is replaced
+fn write_private(path: &Path, content: &[u8]) -> Result<(), ProprietaryError> {
+    let _ = std::fs::remove_file(path);
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(path)
+        .and_then(|mut file| {
+            file.write_all(content)?;
+            file.sync_all()
+        })
+        .map_err(|_| ProprietaryError::FileWrite {
+            message: "proprietary-write",
+            path: path.to_path_buf(),
+        })
+}
+
+/// Writes to a partial file next to the path first and then moves it into place
+fn write_atomically(path: &Path, content: &[u8]) -> Result<(), ProprietaryError> {
+    let mut partial_path = path.as_os_str().to_owned();
+    partial_path.push(".part");
+    let partial_path = PathBuf::from(partial_path);
+
+    std::fs::write(&partial_path, content).map_err(|_| ProprietaryError::FileWrite {
+        message: "proprietary-write",
+        path: partial_path.clone(),
+    })?;
+    std::fs::rename(&partial_path, path).map_err(|_| ProprietaryError::FileWrite {
+        message: "proprietary-move",
+        path: path.to_path_buf(),
+    })
+}
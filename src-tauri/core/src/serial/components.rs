@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+/// The machine id set up by systemd, older systems only have the one of dbus
+const MACHINE_ID_PATHS: [&str; 2] = ["/etc/machine-id", "/var/lib/dbus/machine-id"];
+
+/// Only readable by root on most distributions, which is why it is never part of the serial
+const PRODUCT_UUID_PATH: &str = "/sys/class/dmi/id/product_uuid";
+
+const MOUNT_INFO_PATH: &str = "/proc/self/mountinfo";
+
+const BLOCK_DEVICES_PATH: &str = "/sys/class/block";
+
+/// The files a disk might expose its serial in, depending on its driver
+const DISK_SERIAL_FILES: [&str; 4] = ["device/serial", "serial", "device/wwid", "wwid"];
+
+/// Reads the identifiers the serial is made of, [None] if one could not be read
+///
+/// Both can be read by every user and don't depend on which devices happen to be plugged in.
+/// These are only available on Linux, on every other system all of them are [None]
+pub fn serial_components() -> [(&'static str, Option<String>); 2] {
+    [
+        ("machine-id", machine_id()),
+        ("disk-serial", root_disk_serial()),
+    ]
+}
+
+/// Reads every hardware identifier which helps to explain a changed serial, including the
+/// ones which are not part of it
+pub fn hardware_components() -> [(&'static str, Option<String>); 3] {
+    let [machine_id, disk_serial] = serial_components();
+    [
+        machine_id,
+        disk_serial,
+        (
+            "product-uuid",
+            read_identifier(Path::new(PRODUCT_UUID_PATH)),
+        ),
+    ]
+}
+
+fn machine_id() -> Option<String> {
+    MACHINE_ID_PATHS
+        .iter()
+        .find_map(|path| read_identifier(Path::new(path)))
+}
+
+/// The serial of the disk holding the root filesystem, virtual disks might have none
+fn root_disk_serial() -> Option<String> {
+    let disk = physical_disk(&block_device(&root_device()?)?)?;
+    DISK_SERIAL_FILES
+        .iter()
+        .find_map(|file| read_identifier(&disk.join(file)))
+}
+
+/// The device the root filesystem is mounted from, the last mount of `/` hides all others
+fn root_device() -> Option<PathBuf> {
+    let mount_info = std::fs::read_to_string(MOUNT_INFO_PATH).ok()?;
+    mount_info.lines().rev().find_map(|line| {
+        let (mount, filesystem) = line.split_once(" - ")?;
+        let mount_point = mount.split(' ').nth(4)?;
+        let source = filesystem.split(' ').nth(1)?;
+        (mount_point == "/").then(|| PathBuf::from(source))
+    })
+}
+
+/// Resolves a device like `/dev/mapper/root` to its block device in sysfs
+fn block_device(device: &Path) -> Option<PathBuf> {
+    let name = std::fs::canonicalize(device).ok()?.file_name()?.to_owned();
+    std::fs::canonicalize(Path::new(BLOCK_DEVICES_PATH).join(name)).ok()
+}
+
+/// Follows partitions to their disk and mapped devices like LVM or LUKS to the device they
+/// are backed by, the lowest device name is used if there is more than one
+fn physical_disk(block_device: &Path) -> Option<PathBuf> {
+    let mut device = block_device.to_path_buf();
+    // Bounded, as a broken sysfs should never hang the launcher
+    for _ in 0..16 {
+        let mut backing: Vec<PathBuf> = std::fs::read_dir(device.join("slaves"))
+            .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+            .unwrap_or_default();
+        backing.sort();
+
+        if let Some(backing) = backing.first() {
+            device = std::fs::canonicalize(backing).ok()?;
+        } else if device.join("partition").is_file() {
+            device = device.parent()?.to_path_buf();
+        } else {
+            return Some(device);
+        }
+    }
+    None
+}
+
+/// Reads an identifier from a file, blank ones are treated as missing
+fn read_identifier(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let identifier = content.trim();
+    if identifier.is_empty() {
+        None
+    } else {
+        Some(identifier.to_lowercase())
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{HardwareComponentData, HardwareFingerprintData};
+use crate::storage::{StorageError, StorageType};
+
+/// The name of the serial itself inside the fingerprint
+pub const SERIAL_COMPONENT: &str = "serial";
+
+/// How a single component compares to the one of the last successful login
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ComponentStatus {
+    Unchanged,
+    Changed,
+    /// The component could not be read on the last login but can be now
+    Added,
+    /// The component could be read on the last login but can't be now
+    Removed,
+    /// The component could neither be read now nor on the last login
+    Unavailable,
+    /// No login has been recorded yet, so there is nothing to compare against
+    Unrecorded,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ComponentDiagnostic {
+    pub name: &'static str,
+    pub status: ComponentStatus,
+}
+
+/// Describes which parts of the hardware changed since the last successful login
+#[derive(Clone, Debug, Serialize)]
+pub struct HardwareDiagnostics {
+    /// When the fingerprint was recorded, [None] if no login has been recorded yet
+    pub recorded_at: Option<i64>,
+    pub components: Vec<ComponentDiagnostic>,
+}
+
+/// Records the fingerprint of the current hardware after a successful login
+///
+/// The salt of an existing fingerprint is kept, so the hashes stay comparable. Nothing is
+/// recorded if the operating system can't provide a random salt, the hashes could be guessed
+/// without one
+pub fn record_fingerprint(paths: &LauncherPaths, serial: &str) -> Result<(), StorageError> {
+    let mut salt = load_fingerprint(paths)?.salt;
+    if salt.is_empty() {
+        match generate_salt() {
+            Some(generated) => salt = generated,
+            None => return Ok(()),
+        }
+    }
+
+    let components = current_components(serial)
+        .into_iter()
+        .map(|(name, value)| HardwareComponentData {
+            name: name.to_string(),
+            hash: value.map(|value| hash_component(&salt, name, &value)),
+        })
+        .collect();
+
+    crate::storage::save_storage_data(
+        paths,
+        StorageType::HardwareFingerprint,
+        HardwareFingerprintData {
+            salt,
+            recorded_at: crate::game::current_time_millis(),
+            components,
+        },
+    )
+}
+
+/// Compares the current hardware against the fingerprint of the last successful login
+pub fn diagnose(paths: &LauncherPaths, serial: &str) -> Result<HardwareDiagnostics, StorageError> {
+    let fingerprint = load_fingerprint(paths)?;
+    let recorded = !fingerprint.salt.is_empty();
+
+    let components = current_components(serial)
+        .into_iter()
+        .map(|(name, value)| {
+            let recorded_hash = fingerprint
+                .components
+                .iter()
+                .find(|component| component.name == name)
+                .map(|component| component.hash.as_deref());
+            let current_hash = value.map(|value| hash_component(&fingerprint.salt, name, &value));
+
+            let status = match (recorded_hash, current_hash) {
+                _ if !recorded => ComponentStatus::Unrecorded,
+                // Components added by newer launchers are compared like missing ones
+                (None, None) | (Some(None), None) => ComponentStatus::Unavailable,
+                (None, Some(_)) | (Some(None), Some(_)) => ComponentStatus::Added,
+                (Some(Some(_)), None) => ComponentStatus::Removed,
+                (Some(Some(recorded)), Some(current)) if recorded == current => {
+                    ComponentStatus::Unchanged
+                }
+                (Some(Some(_)), Some(_)) => ComponentStatus::Changed,
+            };
+            ComponentDiagnostic { name, status }
+        })
+        .collect();
+
+    Ok(HardwareDiagnostics {
+        recorded_at: recorded.then_some(fingerprint.recorded_at),
+        components,
+    })
+}
+
+fn load_fingerprint(paths: &LauncherPaths) -> Result<HardwareFingerprintData, StorageError> {
+    crate::storage::load_storage_data(
+        paths,
+        StorageType::HardwareFingerprint,
+        HardwareFingerprintData::default(),
+    )
+    .map(|loaded| loaded.data)
+}
+
+/// The serial sent to the servers followed by every hardware component it might be made of
+fn current_components(serial: &str) -> Vec<(&'static str, Option<String>)> {
+    let serial = Some(serial.to_string()).filter(|serial| !serial.is_empty());
+    std::iter::once((SERIAL_COMPONENT, serial))
+        .chain(crate::serial::components::hardware_components())
+        .collect()
+}
+
+fn hash_component(salt: &str, name: &str, value: &str) -> String {
+    hex::encode(Sha256::digest(format!("{salt}\n{name}\n{value}")))
+}
+
+/// Generates a salt from the random number generator of the operating system
+fn generate_salt() -> Option<String> {
+    let mut salt = [0u8; 16];
+    getrandom::getrandom(&mut salt).ok()?;
+    Some(hex::encode(salt))
+}
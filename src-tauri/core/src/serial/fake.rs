@@ -0,0 +1,23 @@
+use crate::serial::{SerialFuture, SerialProvider};
+
+/// The serial of the [FakeSerialProvider] unless another one is given
+pub const FAKE_SERIAL: &str = "FAKE-SERIAL-0000";
+
+/// Always returns the same serial, for development and tests only
+pub struct FakeSerialProvider {
+    pub serial: String,
+}
+
+impl Default for FakeSerialProvider {
+    fn default() -> Self {
+        FakeSerialProvider {
+            serial: FAKE_SERIAL.to_string(),
+        }
+    }
+}
+
+impl SerialProvider for FakeSerialProvider {
+    fn fetch_serial(&self) -> SerialFuture<'_> {
+        Box::pin(async { Ok(self.serial.clone()) })
+    }
+}
@@ -0,0 +1,43 @@
+use sha2::{Digest, Sha256};
+
+use crate::serial::{SerialError, SerialFuture, SerialProvider};
+
+/// Every serial of this provider is derived with this prefix, so it can never collide with a
+/// hash of the same sources created by anything else
+const SERIAL_DOMAIN: &str = "moon-launcher-serial-v2";
+
+/// Derives the serial from identifiers Linux already provides, without any closed-source code
+///
+/// The machine id and the serial of the disk holding the root filesystem are hashed together.
+/// Both are readable by every user, so the serial is the same whether the launcher runs as root
+/// or not. The machine id is required, a disk without a serial is left out
+pub struct LinuxSerialProvider;
+
+impl SerialProvider for LinuxSerialProvider {
+    fn fetch_serial(&self) -> SerialFuture<'_> {
+        Box::pin(async { derive_serial() })
+    }
+}
+
+fn derive_serial() -> Result<String, SerialError> {
+    if !cfg!(target_os = "linux") {
+        return Err(SerialError::Unsupported {
+            message: "serial-linux-only",
+        });
+    }
+
+    let sources = crate::serial::components::serial_components();
+    if sources[0].1.is_none() {
+        return Err(SerialError::Unavailable {
+            message: "serial-unavailable",
+        });
+    }
+
+    // Every source is labeled, so a missing source can never be mistaken for another one
+    let mut hasher = Sha256::new();
+    hasher.update(SERIAL_DOMAIN);
+    for (label, value) in sources {
+        hasher.update(format!("\n{label}={}", value.unwrap_or_default()));
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+
+use crate::proprietary::ProprietaryError;
+use crate::serial::fake::FakeSerialProvider;
+use crate::serial::linux::LinuxSerialProvider;
+use crate::serial::proprietary::ProprietarySerialProvider;
+use crate::storage::location::LauncherPaths;
+
+pub mod components;
+pub mod diagnostics;
+pub mod fake;
+pub mod linux;
+pub mod proprietary;
+
+/// Chooses the serial provider, see [active_provider]
+pub const SERIAL_PROVIDER_VARIABLE: &str = "MOON_SERIAL_PROVIDER";
+
+/// The future returned by [SerialProvider::fetch_serial]
+pub type SerialFuture<'a> = Pin<Box<dyn Future<Output = Result<String, SerialError>> + Send + 'a>>;
+
+/// Contains all errors which might happen on fetching the serial of this device
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SerialError {
+    Proprietary {
+        message: &'static str,
+        cause: ProprietaryError,
+    },
+    Unavailable {
+        message: &'static str,
+    },
+    Unsupported {
+        message: &'static str,
+    },
+}
+
+impl From<ProprietaryError> for SerialError {
+    fn from(error: ProprietaryError) -> Self {
+        SerialError::Proprietary {
+            message: error.message(),
+            cause: error,
+        }
+    }
+}
+
+/// Provides the serial which identifies this device towards the servers
+///
+/// The serial of a device must never change, otherwise the user has to request a HWID reset
+pub trait SerialProvider: Send + Sync {
+    /// Fetches the serial of this device
+    fn fetch_serial(&self) -> SerialFuture<'_>;
+
+    /// Discards everything cached by the provider, so the next fetch starts from scratch
+    fn reset(&self) -> Result<(), SerialError> {
+        Ok(())
+    }
+}
+
+/// Chooses the serial provider of this launcher
+///
+/// Builds with the `fake-serial` feature always use the [FakeSerialProvider]. Otherwise the
+/// `MOON_SERIAL_PROVIDER` environment variable can select the `linux` provider instead of the
+/// proprietary one, development builds additionally accept `fake`
+pub fn active_provider(paths: &LauncherPaths) -> Box<dyn SerialProvider> {
+    if cfg!(feature = "fake-serial") {
+        return Box::new(FakeSerialProvider::default());
+    }
+
+    match std::env::var(SERIAL_PROVIDER_VARIABLE).as_deref() {
+        Ok("linux") => Box::new(LinuxSerialProvider),
+        Ok("fake") if cfg!(debug_assertions) => Box::new(FakeSerialProvider::default()),
+        _ => Box::new(ProprietarySerialProvider {
+            paths: paths.clone(),
+        }),
+    }
+}
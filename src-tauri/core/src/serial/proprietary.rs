@@ -0,0 +1,20 @@
+use crate::serial::{SerialError, SerialFuture, SerialProvider};
+use crate::storage::location::LauncherPaths;
+
+/// Fetches the serial through the closed-source library, which is what the servers expect
+///
+/// The library is downloaded to the working directory of the paths
+pub struct ProprietarySerialProvider {
+    pub paths: LauncherPaths,
+}
+
+impl SerialProvider for ProprietarySerialProvider {
+    fn fetch_serial(&self) -> SerialFuture<'_> {
+        Box::pin(async { Ok(crate::proprietary::fetch_serial(&self.paths).await?) })
+    }
+
+    /// Downloads the library again on the next fetch unless it is already loaded
+    fn reset(&self) -> Result<(), SerialError> {
+        Ok(crate::proprietary::discard_downloaded_library(&self.paths)?)
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::{PoisonError, RwLock};
+
+use crate::api::moon::auth::Channel;
+
+/// Everything known about the current device and sign-in
+///
+/// Every method only holds the lock for as long as it takes to copy a value, so a slow
+/// request never blocks anything else
+pub trait SessionStore: Send + Sync {
+    /// The serial of this device, empty as long as it hasn't been loaded
+    fn serial(&self) -> String;
+
+    fn set_serial(&self, serial: String);
+
+    /// The session token of the last authentication, empty as long as the user isn't signed in
+    fn session_token(&self) -> String;
+
+    /// All channels the user had access to on the last authentication
+    fn channels(&self) -> Vec<Channel>;
+
+    /// Replaces the session after a successful authentication
+    fn sign_in(&self, session_token: String, channels: Vec<Channel>);
+
+    fn is_signed_in(&self) -> bool {
+        !self.session_token().is_empty()
+    }
+
+    /// Finds a channel the user had access to on the last authentication
+    fn channel(&self, name: &str) -> Option<Channel> {
+        self.channels()
+            .into_iter()
+            .find(|channel| channel.name == name)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Session {
+    serial: String,
+    session_token: String,
+    channels: Vec<Channel>,
+}
+
+/// Keeps the session in memory for as long as the launcher runs
+#[derive(Default)]
+pub struct SessionService {
+    session: RwLock<Session>,
+}
+
+impl SessionService {
+    fn read<T>(&self, read: impl FnOnce(&Session) -> T) -> T {
+        read(&self.session.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn write(&self, write: impl FnOnce(&mut Session)) {
+        write(&mut self.session.write().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl SessionStore for SessionService {
+    fn serial(&self) -> String {
+        self.read(|session| session.serial.clone())
+    }
+
+    fn set_serial(&self, serial: String) {
+        self.write(|session| session.serial = serial)
+    }
+
+    fn session_token(&self) -> String {
+        self.read(|session| session.session_token.clone())
+    }
+
+    fn channels(&self) -> Vec<Channel> {
+        self.read(|session| session.channels.clone())
+    }
+
+    fn sign_in(&self, session_token: String, channels: Vec<Channel>) {
+        self.write(|session| {
+            session.session_token = session_token;
+            session.channels = channels;
+        })
+    }
+
+    fn channel(&self, name: &str) -> Option<Channel> {
+        self.read(|session| {
+            session
+                .channels
+                .iter()
+                .find(|channel| channel.name == name)
+                .cloned()
+        })
+    }
+}
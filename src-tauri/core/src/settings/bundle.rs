@@ -0,0 +1,289 @@
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::settings::{SettingsDocument, SettingsError, SettingsPatch};
+use crate::storage::types::{GameSettingData, LoginSettingData, WineSettingData};
+use crate::storage::StorageError;
+
+/// The current version of the bundle format, bundles of newer launchers are refused
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// All settings of the launcher in a single portable file
+///
+/// Sections and fields missing from an imported bundle are left untouched, so a bundle may
+/// only contain the settings it actually wants to hand out. This is why the sections are kept
+/// as plain JSON until they are merged into the current settings. Profiles are never part of a
+/// bundle, they belong to the instance directories on this machine. Neither is the interface
+/// language, it is chosen by every user for themselves
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsBundle {
+    pub format_version: u32,
+    pub exported_at: i64,
+    pub login: Option<Value>,
+    pub game: Option<Value>,
+    pub versions: Option<Value>,
+    pub updates: Option<Value>,
+    pub wine: Option<Value>,
+}
+
+/// The login settings without the uid, which identifies the account and must never be shared
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BundledLoginData {
+    pub remember_me: bool,
+}
+
+/// A single setting which would be changed by importing a bundle
+#[derive(Clone, Debug, Serialize)]
+pub struct SettingChange {
+    pub section: &'static str,
+    pub field: String,
+    pub current: Value,
+    pub imported: Value,
+}
+
+impl SettingsBundle {
+    /// Creates a bundle containing every section of the document
+    pub fn export(document: &SettingsDocument) -> SettingsBundle {
+        SettingsBundle {
+            format_version: BUNDLE_FORMAT_VERSION,
+            exported_at: crate::game::current_time_millis(),
+            login: serde_json::to_value(BundledLoginData {
+                remember_me: document.login.remember_me,
+            })
+            .ok(),
+            game: serde_json::to_value(shareable_game_settings(
+                document.game.clone(),
+                &GameSettingData::default(),
+            ))
+            .ok(),
+            versions: serde_json::to_value(&document.versions).ok(),
+            updates: serde_json::to_value(document.updates).ok(),
+            wine: serde_json::to_value(shareable_wine_settings(
+                document.wine.clone(),
+                &WineSettingData::default(),
+            ))
+            .ok(),
+        }
+    }
+
+    /// Reads a bundle from a file
+    pub fn read(path: &Path) -> Result<SettingsBundle, SettingsError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| StorageError::io("bundle-read", path, error))?;
+        let bundle: SettingsBundle =
+            serde_json::from_str(&content).map_err(|_| StorageError::Parse {
+                message: "bundle-invalid",
+                path: path.to_path_buf(),
+            })?;
+
+        if bundle.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(SettingsError::Invalid {
+                message: "bundle-newer-version",
+                field: "format_version",
+            });
+        }
+        Ok(bundle)
+    }
+
+    /// Writes the bundle to a file
+    pub fn write(&self, path: &Path) -> Result<(), SettingsError> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|_| StorageError::JsonSerialize {
+                message: "bundle-serialize",
+            })?;
+        std::fs::write(path, content)
+            .map_err(|error| StorageError::io("bundle-write", path, error))?;
+        Ok(())
+    }
+
+    /// Turns the bundle into a patch for the current settings, the uid of the current login
+    /// and all game settings which are never shared are always kept
+    ///
+    /// Every section of the bundle is merged into the current one field by field, so fields
+    /// missing from the bundle keep their current value instead of falling back to the default
+    pub fn into_patch(self, current: &SettingsDocument) -> Result<SettingsPatch, SettingsError> {
+        Ok(SettingsPatch {
+            login: merge_section(&current.login, self.login, "login")?.map(
+                |login: LoginSettingData| LoginSettingData {
+                    uid: current.login.uid,
+                    ..login
+                },
+            ),
+            game: merge_section(&current.game, self.game, "game")?
+                .map(|game| shareable_game_settings(game, &current.game)),
+            versions: merge_section(&current.versions, self.versions, "versions")?,
+            updates: merge_section(&current.updates, self.updates, "updates")?,
+            wine: merge_section(&current.wine, self.wine, "wine")?
+                .map(|wine| shareable_wine_settings(wine, &current.wine)),
+            interface: None,
+            profiles: None,
+        })
+    }
+}
+
+/// Merges an imported section into the current one, [None] if the bundle doesn't contain it
+fn merge_section<T: Serialize + DeserializeOwned>(
+    current: &T,
+    imported: Option<Value>,
+    section: &'static str,
+) -> Result<Option<T>, SettingsError> {
+    let imported = match imported {
+        Some(imported) => imported,
+        None => return Ok(None),
+    };
+
+    let invalid = || SettingsError::Invalid {
+        message: "bundle-invalid",
+        field: section,
+    };
+    let mut merged = serde_json::to_value(current).map_err(|_| invalid())?;
+    merge_json(&mut merged, imported);
+    serde_json::from_value(merged)
+        .map(Some)
+        .map_err(|_| invalid())
+}
+
+/// Merges `imported` into `target`, objects are merged key by key and every other value
+/// replaces the current one
+fn merge_json(target: &mut Value, imported: Value) {
+    match (target, imported) {
+        (Value::Object(target), Value::Object(imported)) => {
+            for (key, value) in imported {
+                merge_json(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, imported) => *target = imported,
+    }
+}
+
+/// JVM options whose value is a size, like `-Xss2M`
+const SIZE_JVM_OPTIONS: [&str; 2] = ["-Xss", "-Xmn"];
+
+/// Replaces all game settings which must not be shared with the ones of `local`
+///
+/// The game directory only makes sense on the machine it was chosen on. The wrapper, the hooks,
+/// the environment and JVM arguments like `-javaagent:` run programs on this machine, importing
+/// them from a file someone handed out would allow running anything. Only JVM arguments which
+/// are known to merely tune the JVM are imported, see [is_shareable_jvm_argument]
+fn shareable_game_settings(game: GameSettingData, local: &GameSettingData) -> GameSettingData {
+    let jvm_arguments = game
+        .jvm_arguments
+        .iter()
+        .filter(|argument| is_shareable_jvm_argument(argument))
+        .chain(
+            local
+                .jvm_arguments
+                .iter()
+                .filter(|argument| !is_shareable_jvm_argument(argument)),
+        )
+        .cloned()
+        .collect();
+
+    GameSettingData {
+        jvm_arguments,
+        game_directory: local.game_directory.clone(),
+        wrapper: local.wrapper.clone(),
+        environment: local.environment.clone(),
+        pre_launch_hook: local.pre_launch_hook.clone(),
+        post_exit_hook: local.post_exit_hook.clone(),
+        ..game
+    }
+}
+
+/// Whether a JVM argument only tunes the JVM, like `-XX:+UseG1GC`, `-XX:MaxGCPauseMillis=50`
+/// or `-Xss2M`
+///
+/// Everything else might run programs or load code, like `-javaagent:`, `-XX:OnError=`,
+/// `-Xbootclasspath/a:` or `@argfile`, and is never imported
+fn is_shareable_jvm_argument(argument: &str) -> bool {
+    let argument = argument.trim();
+    if let Some(flag) = argument.strip_prefix("-XX:") {
+        return match flag.split_once('=') {
+            Some((name, value)) => {
+                !name.is_empty()
+                    && name.chars().all(|c| c.is_ascii_alphanumeric())
+                    && is_size(value)
+            }
+            None => {
+                let name = flag.strip_prefix(['+', '-']).unwrap_or_default();
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        };
+    }
+    SIZE_JVM_OPTIONS
+        .iter()
+        .any(|option| argument.strip_prefix(option).is_some_and(is_size))
+}
+
+/// Whether a value is a plain number with an optional unit, like `32M` or `20`
+fn is_size(value: &str) -> bool {
+    let digits = value.trim_end_matches(['k', 'K', 'm', 'M', 'g', 'G', '%']);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Replaces all wine settings which must not be shared with the ones of `local`, for the same
+/// reasons as [shareable_game_settings]
+fn shareable_wine_settings(wine: WineSettingData, local: &WineSettingData) -> WineSettingData {
+    WineSettingData {
+        binary: local.binary.clone(),
+        prefix: local.prefix.clone(),
+        environment: local.environment.clone(),
+        ..wine
+    }
+}
+
+/// Lists every setting which would change if the patch was applied to the document
+pub fn diff_settings(current: &SettingsDocument, patch: &SettingsPatch) -> Vec<SettingChange> {
+    let mut changes = Vec::new();
+    diff_section(&mut changes, "login", &current.login, &patch.login);
+    diff_section(&mut changes, "game", &current.game, &patch.game);
+    diff_section(&mut changes, "versions", &current.versions, &patch.versions);
+    diff_section(&mut changes, "updates", &current.updates, &patch.updates);
+    diff_section(&mut changes, "wine", &current.wine, &patch.wine);
+    diff_section(
+        &mut changes,
+        "interface",
+        &current.interface,
+        &patch.interface,
+    );
+    diff_section(&mut changes, "profiles", &current.profiles, &patch.profiles);
+    changes
+}
+
+/// Compares the top level fields of a section, nested values are compared as a whole
+fn diff_section<T: Serialize>(
+    changes: &mut Vec<SettingChange>,
+    section: &'static str,
+    current: &T,
+    imported: &Option<T>,
+) {
+    let imported = match imported {
+        Some(imported) => imported,
+        None => return,
+    };
+
+    let (current, imported) = match (
+        serde_json::to_value(current),
+        serde_json::to_value(imported),
+    ) {
+        (Ok(Value::Object(current)), Ok(Value::Object(imported))) => (current, imported),
+        _ => return,
+    };
+
+    for (field, imported_value) in imported {
+        let current_value = current.get(&field).cloned().unwrap_or(Value::Null);
+        if current_value != imported_value {
+            changes.push(SettingChange {
+                section,
+                field,
+                current: current_value,
+                imported: imported_value,
+            });
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use std::sync::{PoisonError, RwLock};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::location::LauncherPaths;
+use crate::storage::types::{
+    GameSettingData, HardwareFingerprintData, InstallationData, InterfaceSettingData,
+    LoginSettingData, ProfileSettingData, UpdateSettingData, VersionSettingData, WineSettingData,
+};
+use crate::storage::{LoadSource, Loaded, StorageError, StorageType};
+
+pub mod bundle;
+pub mod validation;
+
+/// The event emitted to every window once any setting has been changed
+pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+
+/// All settings of the launcher combined, every section is still stored in its own
+/// [StorageType] file inside the working directory
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsDocument {
+    pub login: LoginSettingData,
+    pub game: GameSettingData,
+    pub versions: VersionSettingData,
+    pub updates: UpdateSettingData,
+    pub wine: WineSettingData,
+    pub interface: InterfaceSettingData,
+    pub profiles: ProfileSettingData,
+}
+
+/// A partial [SettingsDocument], only the sections which are set are replaced
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SettingsPatch {
+    pub login: Option<LoginSettingData>,
+    pub game: Option<GameSettingData>,
+    pub versions: Option<VersionSettingData>,
+    pub updates: Option<UpdateSettingData>,
+    pub wine: Option<WineSettingData>,
+    pub interface: Option<InterfaceSettingData>,
+    pub profiles: Option<ProfileSettingData>,
+}
+
+impl SettingsPatch {
+    /// Returns the document with every section of the patch replaced
+    fn apply_to(&self, mut document: SettingsDocument) -> SettingsDocument {
+        let SettingsPatch {
+            login,
+            game,
+            versions,
+            updates,
+            wine,
+            interface,
+            profiles,
+        } = self.clone();
+        document.login = login.unwrap_or(document.login);
+        document.game = game.unwrap_or(document.game);
+        document.versions = versions.unwrap_or(document.versions);
+        document.updates = updates.unwrap_or(document.updates);
+        document.wine = wine.unwrap_or(document.wine);
+        document.interface = interface.unwrap_or(document.interface);
+        document.profiles = profiles.unwrap_or(document.profiles);
+        document
+    }
+}
+
+/// All errors which can occur upon changing settings
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SettingsError {
+    Invalid {
+        message: &'static str,
+        field: &'static str,
+    },
+    Storage {
+        message: &'static str,
+        cause: StorageError,
+    },
+}
+
+impl SettingsError {
+    /// Returns the key of the message describing the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            SettingsError::Invalid { message, .. } | SettingsError::Storage { message, .. } => {
+                message
+            }
+        }
+    }
+}
+
+impl From<StorageError> for SettingsError {
+    fn from(cause: StorageError) -> Self {
+        SettingsError::Storage {
+            message: cause.message(),
+            cause,
+        }
+    }
+}
+
+/// A section which has not been loaded from its own file on startup
+#[derive(Clone, Debug, Serialize)]
+pub enum LoadIssue {
+    /// The file is broken, the backup of the last good version has been loaded instead
+    Restored { storage_type: StorageType },
+    /// Neither the file nor its backup could be loaded, the defaults are used in memory
+    Failed {
+        storage_type: StorageType,
+        cause: StorageError,
+    },
+}
+
+/// A single section of the [SettingsDocument] backed by its own storage file
+pub trait SettingsSection: Clone + Default + Serialize + DeserializeOwned {
+    /// The storage file the section is saved to
+    fn storage_type() -> StorageType;
+
+    fn section(document: &SettingsDocument) -> &Self;
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self;
+
+    /// The section written when there is no file yet and when the section is reset
+    fn initial() -> Self {
+        Self::default()
+    }
+
+    /// Validates the section before it is saved, invalid sections are never stored
+    ///
+    /// Only the first of the [SettingsSection::invalid_fields] is returned
+    fn validate(&self, document: &SettingsDocument) -> Result<(), SettingsError> {
+        match self.invalid_fields(document).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Lists the errors of every invalid field of the section
+    ///
+    /// The document is the one the section is going to be part of, which already contains the
+    /// section itself and every other section changed along with it
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        Vec::new()
+    }
+}
+
+/// Called with the new [SettingsDocument] every time a setting has been changed
+pub type SettingsListener = Box<dyn Fn(&SettingsDocument) + Send + Sync>;
+
+/// The single source of truth for all settings
+///
+/// All settings are loaded once on startup and kept in memory, every change is validated and
+/// saved before it becomes visible to the rest of the launcher
+pub struct SettingsService {
+    paths: LauncherPaths,
+    document: RwLock<SettingsDocument>,
+    listeners: RwLock<Vec<SettingsListener>>,
+    load_issues: Vec<LoadIssue>,
+}
+
+impl SettingsService {
+    /// Loads all settings from the working directory of the paths, which are used for every
+    /// other file of the launcher as well
+    ///
+    /// Sections which fail to load are kept at their defaults in memory, their files are left
+    /// untouched until the user changes a setting of that section. Both these and sections
+    /// restored from their backup are reported by [SettingsService::load_issues]
+    pub fn load(paths: LauncherPaths) -> SettingsService {
+        let mut load_issues = Vec::new();
+        let document = SettingsDocument {
+            login: load_section(&paths, &mut load_issues),
+            game: load_section(&paths, &mut load_issues),
+            versions: load_section(&paths, &mut load_issues),
+            updates: load_section(&paths, &mut load_issues),
+            wine: load_section(&paths, &mut load_issues),
+            interface: load_section(&paths, &mut load_issues),
+            profiles: load_section(&paths, &mut load_issues),
+        };
+        SettingsService {
+            paths,
+            document: RwLock::new(document),
+            listeners: RwLock::new(Vec::new()),
+            load_issues,
+        }
+    }
+
+    /// The directories the settings and all other files of the launcher are stored in
+    pub fn paths(&self) -> &LauncherPaths {
+        &self.paths
+    }
+
+    /// The sections which have not been loaded from their own file on startup
+    pub fn load_issues(&self) -> &[LoadIssue] {
+        &self.load_issues
+    }
+
+    /// Returns a copy of all settings
+    pub fn document(&self) -> SettingsDocument {
+        self.document
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns a copy of a single section
+    pub fn get<T: SettingsSection>(&self) -> T {
+        T::section(&self.document.read().unwrap_or_else(PoisonError::into_inner)).clone()
+    }
+
+    /// Applies a change to a single section, the changed section is validated and saved before
+    /// it replaces the current one
+    pub fn update<T: SettingsSection>(
+        &self,
+        change: impl FnOnce(&mut T),
+    ) -> Result<T, SettingsError> {
+        let mut document = self
+            .document
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let mut section = T::section(&document).clone();
+        change(&mut section);
+        let mut changed = document.clone();
+        *T::section_mut(&mut changed) = section.clone();
+
+        section.validate(&changed)?;
+        crate::storage::save_storage_data(&self.paths, T::storage_type(), &section)?;
+        *document = changed.clone();
+
+        drop(document);
+        self.notify(&changed);
+        Ok(section)
+    }
+
+    /// Replaces every section which is set inside the patch
+    ///
+    /// All sections are validated before anything is saved, so an invalid patch never leaves
+    /// the settings half-applied. If saving a section fails, the sections saved before it are
+    /// restored. A section which cannot be restored either stays changed in memory as well, so
+    /// the settings in memory always match the files
+    pub fn patch(&self, patch: SettingsPatch) -> Result<SettingsDocument, SettingsError> {
+        let mut document = self
+            .document
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let changed = patch.apply_to(document.clone());
+        validate_section(&patch.login, &changed)?;
+        validate_section(&patch.game, &changed)?;
+        validate_section(&patch.versions, &changed)?;
+        validate_section(&patch.updates, &changed)?;
+        validate_section(&patch.wine, &changed)?;
+        validate_section(&patch.interface, &changed)?;
+        validate_section(&patch.profiles, &changed)?;
+
+        let mut saved = Vec::new();
+        let saving = save_section(&self.paths, &mut saved, &patch.login)
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.game))
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.versions))
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.updates))
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.wine))
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.interface))
+            .and_then(|_| save_section(&self.paths, &mut saved, &patch.profiles));
+
+        if let Err(error) = saving {
+            let mut unrestored = false;
+            unrestored |=
+                restore_section::<LoginSettingData>(&self.paths, &saved, &mut document, &changed);
+            unrestored |=
+                restore_section::<GameSettingData>(&self.paths, &saved, &mut document, &changed);
+            unrestored |=
+                restore_section::<VersionSettingData>(&self.paths, &saved, &mut document, &changed);
+            unrestored |=
+                restore_section::<UpdateSettingData>(&self.paths, &saved, &mut document, &changed);
+            unrestored |=
+                restore_section::<WineSettingData>(&self.paths, &saved, &mut document, &changed);
+            unrestored |= restore_section::<InterfaceSettingData>(
+                &self.paths,
+                &saved,
+                &mut document,
+                &changed,
+            );
+            unrestored |=
+                restore_section::<ProfileSettingData>(&self.paths, &saved, &mut document, &changed);
+
+            let current = document.clone();
+            drop(document);
+            if unrestored {
+                self.notify(&current);
+            }
+            return Err(error);
+        }
+
+        *document = changed.clone();
+        drop(document);
+        self.notify(&changed);
+        Ok(changed)
+    }
+
+    /// Resets the section stored in a [StorageType] file to its defaults
+    ///
+    /// Installations are not settings, resetting them without removing the installed files
+    /// would only leave those files behind untracked. The hardware fingerprint isn't a setting
+    /// either, it is replaced on every login
+    pub fn reset(&self, storage_type: StorageType) -> Result<SettingsDocument, SettingsError> {
+        match storage_type {
+            StorageType::Login => self.reset_section::<LoginSettingData>(),
+            StorageType::GameSettings => self.reset_section::<GameSettingData>(),
+            StorageType::WineSettings => self.reset_section::<WineSettingData>(),
+            StorageType::VersionSettings => self.reset_section::<VersionSettingData>(),
+            StorageType::UpdateSettings => self.reset_section::<UpdateSettingData>(),
+            StorageType::InterfaceSettings => self.reset_section::<InterfaceSettingData>(),
+            StorageType::Profiles => self.reset_section::<ProfileSettingData>(),
+            StorageType::Installations => {
+                return Err(SettingsError::Invalid {
+                    message: "settings-reset-installations",
+                    field: "storage_type",
+                })
+            }
+            StorageType::HardwareFingerprint => {
+                return Err(SettingsError::Invalid {
+                    message: "settings-reset-hardware",
+                    field: "storage_type",
+                })
+            }
+        }?;
+        Ok(self.document())
+    }
+
+    fn reset_section<T: SettingsSection>(&self) -> Result<(), SettingsError> {
+        self.update(|section: &mut T| *section = T::initial())?;
+        Ok(())
+    }
+
+    /// Reloads the section stored in a [StorageType] file after it has been changed on disk
+    ///
+    /// The file is validated like any other change, invalid files are rejected and the current
+    /// section is kept. Returns whether the section actually changed, which is not the case for
+    /// files the launcher has written itself
+    ///
+    /// Installations and the hardware fingerprint are not settings, their files are only parsed
+    /// so broken ones are still reported
+    pub fn reload(&self, storage_type: StorageType) -> Result<bool, SettingsError> {
+        match storage_type {
+            StorageType::Login => self.reload_section::<LoginSettingData>(),
+            StorageType::GameSettings => self.reload_section::<GameSettingData>(),
+            StorageType::WineSettings => self.reload_section::<WineSettingData>(),
+            StorageType::VersionSettings => self.reload_section::<VersionSettingData>(),
+            StorageType::UpdateSettings => self.reload_section::<UpdateSettingData>(),
+            StorageType::InterfaceSettings => self.reload_section::<InterfaceSettingData>(),
+            StorageType::Profiles => self.reload_section::<ProfileSettingData>(),
+            StorageType::Installations => {
+                crate::storage::read_storage_data::<InstallationData>(&self.paths, storage_type)?;
+                Ok(false)
+            }
+            StorageType::HardwareFingerprint => {
+                crate::storage::read_storage_data::<HardwareFingerprintData>(
+                    &self.paths,
+                    storage_type,
+                )?;
+                Ok(false)
+            }
+        }
+    }
+
+    fn reload_section<T: SettingsSection>(&self) -> Result<bool, SettingsError> {
+        let section: T = crate::storage::read_storage_data(&self.paths, T::storage_type())?;
+
+        let mut document = self
+            .document
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if serde_json::to_value(&section).ok() == serde_json::to_value(T::section(&document)).ok() {
+            return Ok(false);
+        }
+
+        let mut changed = document.clone();
+        *T::section_mut(&mut changed) = section.clone();
+        section.validate(&changed)?;
+        *T::section_mut(&mut document) = section;
+
+        let changed = document.clone();
+        drop(document);
+        self.notify(&changed);
+        Ok(true)
+    }
+
+    /// Registers a listener which is called after every change
+    pub fn subscribe(&self, listener: SettingsListener) {
+        self.listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(listener);
+    }
+
+    fn notify(&self, document: &SettingsDocument) {
+        for listener in self
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+        {
+            listener(document);
+        }
+    }
+}
+
+/// The problems found in a single storage file by [verify_storage_files]
+#[derive(Debug, Serialize)]
+pub struct FileVerification {
+    pub storage_type: StorageType,
+    /// Empty if the file is fine
+    pub errors: Vec<SettingsError>,
+}
+
+/// Checks every storage file which exists, missing files simply mean the defaults are used
+///
+/// The files are read as they are written, neither the backups nor the settings in memory are
+/// used. Every section is validated against a document of all stored sections, sections which
+/// cannot be read are kept at their defaults inside of it
+pub fn verify_storage_files(paths: &LauncherPaths) -> Vec<FileVerification> {
+    let mut verifications = Vec::new();
+    let document = SettingsDocument {
+        login: read_stored_section(paths, &mut verifications),
+        game: read_stored_section(paths, &mut verifications),
+        versions: read_stored_section(paths, &mut verifications),
+        updates: read_stored_section(paths, &mut verifications),
+        wine: read_stored_section(paths, &mut verifications),
+        interface: read_stored_section(paths, &mut verifications),
+        profiles: read_stored_section(paths, &mut verifications),
+    };
+    validate_stored_section::<LoginSettingData>(&document, &mut verifications);
+    validate_stored_section::<GameSettingData>(&document, &mut verifications);
+    validate_stored_section::<VersionSettingData>(&document, &mut verifications);
+    validate_stored_section::<UpdateSettingData>(&document, &mut verifications);
+    validate_stored_section::<WineSettingData>(&document, &mut verifications);
+    validate_stored_section::<InterfaceSettingData>(&document, &mut verifications);
+    validate_stored_section::<ProfileSettingData>(&document, &mut verifications);
+
+    verify_stored_data::<InstallationData>(paths, StorageType::Installations, &mut verifications);
+    verify_stored_data::<HardwareFingerprintData>(
+        paths,
+        StorageType::HardwareFingerprint,
+        &mut verifications,
+    );
+    verifications
+}
+
+/// Reads a section for [verify_storage_files], falling back to its default if it cannot be read
+fn read_stored_section<T: SettingsSection>(
+    paths: &LauncherPaths,
+    verifications: &mut Vec<FileVerification>,
+) -> T {
+    verify_stored_data(paths, T::storage_type(), verifications).unwrap_or_default()
+}
+
+/// Reads a storage file if it exists and records whether it could be read
+fn verify_stored_data<T: DeserializeOwned>(
+    paths: &LauncherPaths,
+    storage_type: StorageType,
+    verifications: &mut Vec<FileVerification>,
+) -> Option<T> {
+    if !crate::storage::storage_path(paths, storage_type).exists() {
+        return None;
+    }
+
+    let (data, errors) = match crate::storage::read_storage_data(paths, storage_type) {
+        Ok(data) => (Some(data), Vec::new()),
+        Err(cause) => (None, vec![SettingsError::from(cause)]),
+    };
+    verifications.push(FileVerification {
+        storage_type,
+        errors,
+    });
+    data
+}
+
+/// Validates a section which has been read by [read_stored_section]
+fn validate_stored_section<T: SettingsSection>(
+    document: &SettingsDocument,
+    verifications: &mut [FileVerification],
+) {
+    if let Some(verification) = verifications.iter_mut().find(|verification| {
+        verification.storage_type == T::storage_type() && verification.errors.is_empty()
+    }) {
+        verification.errors = T::section(document).invalid_fields(document);
+    }
+}
+
+/// Loads a single section, falling back to its default if it cannot be loaded
+fn load_section<T: SettingsSection>(paths: &LauncherPaths, load_issues: &mut Vec<LoadIssue>) -> T {
+    let storage_type = T::storage_type();
+    match crate::storage::load_storage_data(paths, storage_type, T::initial()) {
+        Ok(Loaded {
+            data,
+            source: LoadSource::Backup,
+        }) => {
+            load_issues.push(LoadIssue::Restored { storage_type });
+            data
+        }
+        Ok(loaded) => loaded.data,
+        Err(cause) => {
+            load_issues.push(LoadIssue::Failed {
+                storage_type,
+                cause,
+            });
+            T::default()
+        }
+    }
+}
+
+fn validate_section<T: SettingsSection>(
+    section: &Option<T>,
+    document: &SettingsDocument,
+) -> Result<(), SettingsError> {
+    match section {
+        Some(section) => section.validate(document),
+        None => Ok(()),
+    }
+}
+
+/// Saves a section if it is set and remembers that it has been saved
+fn save_section<T: SettingsSection>(
+    paths: &LauncherPaths,
+    saved: &mut Vec<StorageType>,
+    section: &Option<T>,
+) -> Result<(), SettingsError> {
+    if let Some(section) = section {
+        crate::storage::save_storage_data(paths, T::storage_type(), section)?;
+        saved.push(T::storage_type());
+    }
+    Ok(())
+}
+
+/// Saves the previous version of a section again if it has been saved as part of a failed
+/// patch, returns whether it could not be restored and has been replaced in memory instead
+fn restore_section<T: SettingsSection>(
+    paths: &LauncherPaths,
+    saved: &[StorageType],
+    document: &mut SettingsDocument,
+    changed: &SettingsDocument,
+) -> bool {
+    if !saved.contains(&T::storage_type()) {
+        return false;
+    }
+    if crate::storage::save_storage_data(paths, T::storage_type(), T::section(document)).is_ok() {
+        return false;
+    }
+    *T::section_mut(document) = T::section(changed).clone();
+    true
+}
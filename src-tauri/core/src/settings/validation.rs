@@ -0,0 +1,434 @@
+use std::collections::BTreeMap;
+
+use crate::game::memory::{recommended_memory, system_memory};
+use crate::settings::{SettingsDocument, SettingsError, SettingsSection};
+use crate::storage::types::{
+    GameSettingData, InstanceProfileData, InterfaceSettingData, JvmPreset, LoginSettingData,
+    ProfileSettingData, UpdateSettingData, VersionSettingData, WineRunnerKind, WineSettingData,
+};
+use crate::storage::StorageType;
+
+/// Polling more often than this (in minutes) would only cause unnecessary load on our servers
+pub const MINIMUM_POLL_INTERVAL: u64 = 5;
+
+/// The least amount of memory (in MiB) the game is able to start with
+pub const MINIMUM_MEMORY: i64 = 512;
+
+impl SettingsSection for LoginSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::Login
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.login
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.login
+    }
+
+    /// A uid of -1 means that no uid has been entered yet
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        if self.uid < -1 {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-invalid-uid",
+                field: "uid",
+            });
+        }
+        invalid
+    }
+}
+
+impl SettingsSection for GameSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::GameSettings
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.game
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.game
+    }
+
+    /// The memory is recommended for this system once and then kept like any other setting
+    fn initial() -> Self {
+        GameSettingData {
+            memory: recommended_memory(system_memory(), None),
+            ..GameSettingData::default()
+        }
+    }
+
+    fn invalid_fields(&self, document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        invalid.extend(validate_memory(self.memory).err());
+
+        if matches!(self.min_memory, Some(min_memory) if min_memory < 1 || min_memory > self.memory)
+        {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-invalid-minimum-memory",
+                field: "min_memory",
+            });
+        }
+
+        // Relative paths would depend on the directory the launcher has been started from
+        if matches!(self.game_directory, Some(ref directory) if !directory.is_absolute()) {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-relative-game-directory",
+                field: "game_directory",
+            });
+        }
+
+        // Profiles are started with the preset of the game settings, so changing it must not
+        // turn the arguments of a profile invalid
+        invalid.extend(
+            validate_jvm_arguments(
+                &self.jvm_arguments,
+                self.min_memory.is_some(),
+                self.jvm_preset,
+            )
+            .and_then(|_| {
+                document
+                    .profiles
+                    .profiles
+                    .iter()
+                    .try_for_each(|profile| validate_profile_jvm_arguments(profile, self))
+            })
+            .err(),
+        );
+
+        if self.window_width == Some(0) || self.window_height == Some(0) {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-invalid-window-size",
+                field: "window_width",
+            });
+        } else if self.window_width.is_some() != self.window_height.is_some() {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-incomplete-window-size",
+                field: "window_width",
+            });
+        }
+
+        if self.wrapper.iter().any(|part| part.trim().is_empty()) {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-empty-wrapper-argument",
+                field: "wrapper",
+            });
+        }
+
+        invalid.extend(validate_environment(&self.environment).err());
+
+        if matches!(self.pre_launch_hook, Some(ref hook) if hook.trim().is_empty())
+            || matches!(self.post_exit_hook, Some(ref hook) if hook.trim().is_empty())
+        {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-empty-hook",
+                field: "pre_launch_hook",
+            });
+        }
+        invalid
+    }
+}
+
+/// Rejects environment variables which can't be set
+fn validate_environment(environment: &BTreeMap<String, String>) -> Result<(), SettingsError> {
+    if environment
+        .keys()
+        .any(|key| key.is_empty() || key.contains(['=', '\0']))
+    {
+        return Err(SettingsError::Invalid {
+            message: "settings-invalid-environment",
+            field: "environment",
+        });
+    }
+    Ok(())
+}
+
+/// Rejects memory (in MiB) the game can't start with or the system doesn't have
+fn validate_memory(memory: i64) -> Result<(), SettingsError> {
+    if memory < MINIMUM_MEMORY {
+        return Err(SettingsError::Invalid {
+            message: "settings-too-little-memory",
+            field: "memory",
+        });
+    }
+
+    if matches!(system_memory(), Some(system) if memory > system.total) {
+        return Err(SettingsError::Invalid {
+            message: "settings-too-much-memory",
+            field: "memory",
+        });
+    }
+    Ok(())
+}
+
+/// Rejects memory below the minimum of the channel it is used for, channels without a minimum
+/// accept everything the game settings accept
+pub fn validate_channel_memory(
+    memory: i64,
+    channel_minimum: Option<i64>,
+) -> Result<(), SettingsError> {
+    if matches!(channel_minimum, Some(minimum) if memory < minimum) {
+        return Err(SettingsError::Invalid {
+            message: "settings-channel-memory",
+            field: "memory",
+        });
+    }
+    Ok(())
+}
+
+/// Rejects JVM arguments which conflict with the settings the launcher passes itself
+///
+/// The maximum memory is always set by the launcher, the initial memory and garbage collector
+/// only if they have been configured
+fn validate_jvm_arguments(
+    arguments: &[String],
+    min_memory: bool,
+    preset: JvmPreset,
+) -> Result<(), SettingsError> {
+    for argument in arguments {
+        if argument.trim().is_empty() {
+            return Err(SettingsError::Invalid {
+                message: "settings-empty-jvm-argument",
+                field: "jvm_arguments",
+            });
+        }
+
+        if argument.starts_with("-Xmx") {
+            return Err(SettingsError::Invalid {
+                message: "settings-jvm-maximum-memory",
+                field: "jvm_arguments",
+            });
+        }
+
+        if min_memory && argument.starts_with("-Xms") {
+            return Err(SettingsError::Invalid {
+                message: "settings-jvm-minimum-memory",
+                field: "jvm_arguments",
+            });
+        }
+
+        if preset != JvmPreset::None && argument.starts_with("-XX:+Use") && argument.ends_with("GC")
+        {
+            return Err(SettingsError::Invalid {
+                message: "settings-jvm-garbage-collector",
+                field: "jvm_arguments",
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validates the JVM arguments of a profile against the settings it is actually started with,
+/// see [crate::game::profile::launch_options]
+fn validate_profile_jvm_arguments(
+    profile: &InstanceProfileData,
+    game: &GameSettingData,
+) -> Result<(), SettingsError> {
+    let memory = profile.memory.unwrap_or(game.memory);
+    let min_memory = game.min_memory.filter(|min_memory| *min_memory <= memory);
+    validate_jvm_arguments(
+        &profile.jvm_arguments,
+        min_memory.is_some(),
+        game.jvm_preset,
+    )
+}
+
+impl SettingsSection for VersionSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::VersionSettings
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.versions
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.versions
+    }
+
+    /// Only the first invalid selection is reported, as all of them share the same field
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        let invalid = self
+            .selections
+            .iter()
+            .enumerate()
+            .find_map(|(index, selection)| {
+                if selection.channel.is_empty() {
+                    return Some(SettingsError::Invalid {
+                        message: "settings-selection-channel",
+                        field: "selections",
+                    });
+                }
+
+                self.selections[..index]
+                    .iter()
+                    .any(|previous| previous.channel == selection.channel)
+                    .then_some(SettingsError::Invalid {
+                        message: "settings-duplicate-selection",
+                        field: "selections",
+                    })
+            });
+        invalid.into_iter().collect()
+    }
+}
+
+impl SettingsSection for UpdateSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::UpdateSettings
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.updates
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.updates
+    }
+
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        if self.poll_interval < MINIMUM_POLL_INTERVAL {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-update-interval",
+                field: "poll_interval",
+            });
+        }
+
+        if self.retained_versions == 0 {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-keep-versions",
+                field: "retained_versions",
+            });
+        }
+        invalid
+    }
+}
+
+impl SettingsSection for WineSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::WineSettings
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.wine
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.wine
+    }
+
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        if self.enabled && self.runner == WineRunnerKind::Proton && self.binary.is_none() {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-proton-script",
+                field: "binary",
+            });
+        } else if matches!(self.binary, Some(ref binary) if !binary.is_absolute()) {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-relative-runner",
+                field: "binary",
+            });
+        }
+
+        if matches!(self.prefix, Some(ref prefix) if !prefix.is_absolute()) {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-relative-prefix",
+                field: "prefix",
+            });
+        }
+
+        invalid.extend(validate_environment(&self.environment).err());
+        invalid
+    }
+}
+
+impl SettingsSection for InterfaceSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::InterfaceSettings
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.interface
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.interface
+    }
+
+    /// Locales without a catalog are allowed, they simply fall back to another one
+    fn invalid_fields(&self, _document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        if matches!(self.locale, Some(ref locale) if crate::locale::parse_locale(locale).is_none())
+        {
+            invalid.push(SettingsError::Invalid {
+                message: "settings-invalid-locale",
+                field: "locale",
+            });
+        }
+        invalid
+    }
+}
+
+impl SettingsSection for ProfileSettingData {
+    fn storage_type() -> StorageType {
+        StorageType::Profiles
+    }
+
+    fn section(document: &SettingsDocument) -> &Self {
+        &document.profiles
+    }
+
+    fn section_mut(document: &mut SettingsDocument) -> &mut Self {
+        &mut document.profiles
+    }
+
+    /// Every profile is checked on its own, so a field might be reported once for each profile
+    fn invalid_fields(&self, document: &SettingsDocument) -> Vec<SettingsError> {
+        let mut invalid = Vec::new();
+        for (index, profile) in self.profiles.iter().enumerate() {
+            // The id names the instance directory, which is removed along with the profile
+            if !crate::storage::is_directory_name(&profile.id)
+                || self.profiles[..index]
+                    .iter()
+                    .any(|previous| previous.id == profile.id)
+            {
+                invalid.push(SettingsError::Invalid {
+                    message: "settings-profile-id",
+                    field: "id",
+                });
+            }
+
+            if profile.name.trim().is_empty() {
+                invalid.push(SettingsError::Invalid {
+                    message: "settings-profile-name",
+                    field: "name",
+                });
+            }
+
+            if profile.selection.channel.is_empty() {
+                invalid.push(SettingsError::Invalid {
+                    message: "settings-profile-channel",
+                    field: "selection",
+                });
+            }
+
+            if let Some(memory) = profile.memory {
+                invalid.extend(validate_memory(memory).err());
+            }
+
+            invalid.extend(validate_profile_jvm_arguments(profile, &document.game).err());
+
+            if matches!(profile.game_directory, Some(ref directory) if !directory.is_absolute()) {
+                invalid.push(SettingsError::Invalid {
+                    message: "settings-relative-game-directory",
+                    field: "game_directory",
+                });
+            }
+        }
+        invalid
+    }
+}
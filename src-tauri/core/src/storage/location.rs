@@ -0,0 +1,236 @@
+use directories::BaseDirs;
+use serde::{Deserialize, Serialize};
+
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+use crate::storage::types::{GameSettingData, InstallationData};
+use crate::storage::{NamedStorage, StorageType};
+
+/// The working directory can be moved by setting this environment variable
+pub const MOON_HOME_VARIABLE: &str = "MOON_HOME";
+
+/// The minecraft directory can be moved by setting this environment variable
+pub const MOON_MINECRAFT_DIR_VARIABLE: &str = "MOON_MINECRAFT_DIR";
+
+/// If a file with this name exists next to the executable, all data is kept next to it as well
+pub const PORTABLE_MARKER: &str = "portable";
+
+/// Contains all errors which might happen on resolving or creating the directories
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum StorageLocationError {
+    BaseDirectoriesMissing {
+        message: &'static str,
+    },
+    UnableToCreateWorkingDirectory {
+        message: &'static str,
+        path: PathBuf,
+    },
+    UnableToCreateMinecraftDirectory {
+        message: &'static str,
+        path: PathBuf,
+    },
+}
+
+impl StorageLocationError {
+    /// Returns the key of the message describing the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            StorageLocationError::BaseDirectoriesMissing { message }
+            | StorageLocationError::UnableToCreateWorkingDirectory { message, .. }
+            | StorageLocationError::UnableToCreateMinecraftDirectory { message, .. } => message,
+        }
+    }
+}
+
+/// The directories the launcher keeps its data in
+///
+/// Resolving the paths never creates anything, the directories are only created once
+/// something is actually written to them
+#[derive(Clone, Debug)]
+pub struct LauncherPaths {
+    working_directory: PathBuf,
+    minecraft_directory: PathBuf,
+}
+
+impl LauncherPaths {
+    pub fn new(working_directory: PathBuf, minecraft_directory: PathBuf) -> LauncherPaths {
+        LauncherPaths {
+            working_directory,
+            minecraft_directory,
+        }
+    }
+
+    /// Resolves the paths from the environment, see [resolve_working_directory] and
+    /// [resolve_minecraft_directory]
+    pub fn resolve() -> Result<LauncherPaths, StorageLocationError> {
+        let working_directory = resolve_working_directory()?;
+        let minecraft_directory = resolve_minecraft_directory(&working_directory)?;
+        Ok(LauncherPaths::new(working_directory, minecraft_directory))
+    }
+
+    /// The directory containing all launcher settings, which might not exist yet
+    pub fn working_directory(&self) -> &Path {
+        &self.working_directory
+    }
+
+    /// The directory the game is installed to, which might not exist yet
+    pub fn minecraft_directory(&self) -> &Path {
+        &self.minecraft_directory
+    }
+
+    /// The directory a version of a channel is installed to, see [version_folder]
+    pub fn version_directory(&self, channel: &str, version: &str) -> PathBuf {
+        self.minecraft_directory
+            .join("versions")
+            .join(version_folder(channel, version))
+    }
+
+    /// Creates the working directory if it doesn't exist yet
+    pub fn create_working_directory(&self) -> Result<&Path, StorageLocationError> {
+        create_dir_all(&self.working_directory).map_err(|_| {
+            StorageLocationError::UnableToCreateWorkingDirectory {
+                message: "location-working-directory",
+                path: self.working_directory.clone(),
+            }
+        })?;
+        Ok(&self.working_directory)
+    }
+
+    /// Creates the minecraft directory if it doesn't exist yet
+    pub fn create_minecraft_directory(&self) -> Result<&Path, StorageLocationError> {
+        create_dir_all(&self.minecraft_directory).map_err(|_| {
+            StorageLocationError::UnableToCreateMinecraftDirectory {
+                message: "launch-game-directory",
+                path: self.minecraft_directory.clone(),
+            }
+        })?;
+        Ok(&self.minecraft_directory)
+    }
+}
+
+/// The folder name of a version inside the versions directory, like `moon-release-1.0`
+///
+/// The minecraft directory might be shared with the official launcher, the prefix and channel
+/// keep the versions of this launcher apart from its versions and from each other
+pub fn version_folder(channel: &str, version: &str) -> String {
+    format!("moon-{channel}-{version}")
+}
+
+/// Resolves the working directory of the launcher
+///
+/// The first of these which applies is used
+/// - The directory set by the `MOON_HOME` environment variable
+/// - In portable mode: `data/` next to the executable
+/// - Windows: %APPDATA%/.moon
+/// - Linux: ~/.local/share/.moon
+/// - macOS: ~/Library/Application Support/.moon
+pub fn resolve_working_directory() -> Result<PathBuf, StorageLocationError> {
+    if let Some(dir) = environment_directory(MOON_HOME_VARIABLE) {
+        return Ok(dir);
+    }
+
+    if let Some(dir) = portable_directory() {
+        return Ok(dir.join("data"));
+    }
+
+    Ok(base_directories()?.data_dir().join(".moon"))
+}
+
+/// Resolves the minecraft directory of the launcher
+///
+/// The first of these which applies is used
+/// - The directory set by the `MOON_MINECRAFT_DIR` environment variable
+/// - The game directory chosen by the user inside the game settings
+/// - In portable mode: `minecraft/` next to the executable
+/// - The minecraft directory this launcher already installed versions to
+/// - The minecraft directory older versions of this launcher created inside the data directory
+/// - The minecraft directory of the official launcher, so the game files of fresh setups are
+///   shared with it
+pub fn resolve_minecraft_directory(
+    working_directory: &Path,
+) -> Result<PathBuf, StorageLocationError> {
+    if let Some(dir) = environment_directory(MOON_MINECRAFT_DIR_VARIABLE)
+        .or_else(|| chosen_game_directory(working_directory))
+        .or_else(|| portable_directory().map(|dir| dir.join("minecraft")))
+    {
+        return Ok(dir);
+    }
+
+    let user_base_dirs = base_directories()?;
+    let legacy = user_base_dirs.data_dir().join(".minecraft");
+    let official = official_minecraft_directory(&user_base_dirs);
+
+    let recorded = recorded_versions(working_directory);
+    if let Some(used) = [&legacy, &official].into_iter().find(|candidate| {
+        recorded
+            .iter()
+            .any(|folder| candidate.join("versions").join(folder).is_dir())
+    }) {
+        return Ok(used.clone());
+    }
+
+    if legacy.is_dir() {
+        return Ok(legacy);
+    }
+    Ok(official)
+}
+
+fn base_directories() -> Result<BaseDirs, StorageLocationError> {
+    BaseDirs::new().ok_or(StorageLocationError::BaseDirectoriesMissing {
+        message: "location-home-missing",
+    })
+}
+
+/// Reads a directory from an environment variable, empty values are ignored
+fn environment_directory(variable: &str) -> Option<PathBuf> {
+    std::env::var_os(variable)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Returns the directory of the executable if the portable marker exists next to it
+fn portable_directory() -> Option<PathBuf> {
+    let executable = std::env::current_exe().ok()?;
+    let directory = executable.parent()?;
+    if directory.join(PORTABLE_MARKER).is_file() {
+        Some(directory.to_path_buf())
+    } else {
+        None
+    }
+}
+
+/// Reads the game directory chosen by the user from the game settings inside the working
+/// directory
+fn chosen_game_directory(working_directory: &Path) -> Option<PathBuf> {
+    let storage_type = StorageType::GameSettings;
+    let path = working_directory.join(format!("{}.json", storage_type.file_name()));
+    crate::storage::read_storage_file::<GameSettingData>(&storage_type, &path)
+        .ok()?
+        .game_directory
+}
+
+/// Reads the folders of the versions recorded in the installations inside the working directory
+fn recorded_versions(working_directory: &Path) -> Vec<String> {
+    let storage_type = StorageType::Installations;
+    let path = working_directory.join(format!("{}.json", storage_type.file_name()));
+    crate::storage::read_storage_file::<InstallationData>(&storage_type, &path)
+        .map(|data| {
+            data.installations
+                .into_iter()
+                .map(|installation| version_folder(&installation.channel, &installation.version))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The minecraft directory used by the official launcher on this operating system
+fn official_minecraft_directory(user_base_dirs: &BaseDirs) -> PathBuf {
+    if cfg!(target_os = "macos") {
+        user_base_dirs.data_dir().join("minecraft")
+    } else if cfg!(windows) {
+        user_base_dirs.data_dir().join(".minecraft")
+    } else {
+        user_base_dirs.home_dir().join(".minecraft")
+    }
+}
@@ -0,0 +1,62 @@
+use serde_json::{Map, Value};
+
+use crate::storage::types::DEFAULT_RETAINED_VERSIONS;
+use crate::storage::StorageType;
+
+/// The field every storage file stores its schema version in
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Upgrades the JSON content of a storage file by exactly one schema version
+pub type Migration = fn(&mut Map<String, Value>);
+
+pub trait MigratedStorage {
+    /// Fetches all migrations of a storage implementation, the migration at index `n` upgrades
+    /// a file from schema version `n` to `n + 1`
+    fn migrations(&self) -> &'static [Migration];
+
+    /// The schema version files are currently written in
+    fn schema_version(&self) -> u64 {
+        self.migrations().len() as u64
+    }
+}
+
+impl MigratedStorage for StorageType {
+    fn migrations(&self) -> &'static [Migration] {
+        match self {
+            StorageType::Login => &[mark_versioned],
+            StorageType::GameSettings => &[mark_versioned],
+            StorageType::WineSettings => &[mark_versioned],
+            StorageType::VersionSettings => &[mark_versioned],
+            StorageType::UpdateSettings => &[add_retained_versions],
+            StorageType::Installations => &[mark_versioned],
+            StorageType::Profiles => &[mark_versioned],
+            StorageType::HardwareFingerprint => &[mark_versioned],
+            StorageType::InterfaceSettings => &[mark_versioned],
+        }
+    }
+}
+
+/// Upgrades the JSON content of a storage file to the current schema version step by step
+///
+/// Files written before schema versions were introduced are treated as version 0. Files written
+/// by a newer launcher are left as they are, their unknown fields are simply ignored upon parsing
+pub fn migrate(storage_type: &StorageType, content: &mut Map<String, Value>) {
+    let version = content
+        .get(SCHEMA_VERSION_FIELD)
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    for migration in storage_type.migrations().iter().skip(version as usize) {
+        migration(content);
+    }
+}
+
+/// Files written before schema versions were introduced already match the first version
+fn mark_versioned(_: &mut Map<String, Value>) {}
+
+/// The retention limit was added after the update settings had already been released
+fn add_retained_versions(content: &mut Map<String, Value>) {
+    content
+        .entry("retained_versions")
+        .or_insert(Value::from(DEFAULT_RETAINED_VERSIONS));
+}
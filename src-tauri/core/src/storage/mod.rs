@@ -0,0 +1,380 @@
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::storage::location::{LauncherPaths, StorageLocationError};
+use crate::storage::migration::{MigratedStorage, SCHEMA_VERSION_FIELD};
+
+pub mod location;
+pub mod migration;
+pub mod types;
+pub mod usage;
+
+pub trait NamedStorage {
+    /// Fetches the file name for a named storage implementation
+    fn file_name(&self) -> &'static str;
+}
+
+/// All different storage types available (all storages listed are saved in JSON format)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum StorageType {
+    Login,
+    GameSettings,
+    WineSettings,
+    VersionSettings,
+    UpdateSettings,
+    Installations,
+    Profiles,
+    HardwareFingerprint,
+    InterfaceSettings,
+}
+
+/// All errors which can occur upon saving or loading config files
+///
+/// I/O and parse errors are kept apart, a file which cannot be read is a very different
+/// problem than a file which contains garbage
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum StorageError {
+    /// Reading or writing a file failed, `kind` is the [std::io::ErrorKind] of the underlying error
+    Io {
+        message: &'static str,
+        path: PathBuf,
+        kind: String,
+    },
+    /// A file exists but neither it nor its backup contain valid data
+    Parse {
+        message: &'static str,
+        path: PathBuf,
+    },
+    /// The data itself could not be serialized
+    JsonSerialize { message: &'static str },
+    /// The working directory could not be resolved or created
+    Location {
+        message: &'static str,
+        cause: StorageLocationError,
+    },
+    /// The files might be used by other launchers as well, so they are never removed
+    Shared { message: &'static str },
+}
+
+impl StorageError {
+    /// Returns the key of the message describing the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            StorageError::Io { message, .. }
+            | StorageError::Parse { message, .. }
+            | StorageError::JsonSerialize { message }
+            | StorageError::Location { message, .. }
+            | StorageError::Shared { message } => message,
+        }
+    }
+
+    /// Creates an [StorageError::Io] from the underlying error and the path it occurred on
+    pub fn io(message: &'static str, path: &Path, error: std::io::Error) -> StorageError {
+        StorageError::Io {
+            message,
+            path: path.to_path_buf(),
+            kind: format!("{:?}", error.kind()),
+        }
+    }
+}
+
+impl From<StorageLocationError> for StorageError {
+    fn from(cause: StorageLocationError) -> Self {
+        StorageError::Location {
+            message: cause.message(),
+            cause,
+        }
+    }
+}
+
+/// Where the data returned by [load_storage_data] came from
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LoadSource {
+    File,
+    Default,
+    Backup,
+}
+
+/// Data loaded by [load_storage_data] alongside its [LoadSource]
+#[derive(Clone, Debug, Serialize)]
+pub struct Loaded<T> {
+    pub data: T,
+    pub source: LoadSource,
+}
+
+impl StorageType {
+    pub const ALL: [StorageType; 9] = [
+        StorageType::Login,
+        StorageType::GameSettings,
+        StorageType::WineSettings,
+        StorageType::VersionSettings,
+        StorageType::UpdateSettings,
+        StorageType::Installations,
+        StorageType::Profiles,
+        StorageType::HardwareFingerprint,
+        StorageType::InterfaceSettings,
+    ];
+
+    /// Finds the storage type a file inside the working directory belongs to
+    ///
+    /// Temporary and backup files don't belong to any storage type
+    pub fn from_path(path: &Path) -> Option<StorageType> {
+        let file_name = path.file_name()?.to_str()?;
+        StorageType::ALL
+            .into_iter()
+            .find(|storage_type| file_name == format!("{}.json", storage_type.file_name()))
+    }
+}
+
+impl NamedStorage for StorageType {
+    fn file_name(&self) -> &'static str {
+        match self {
+            StorageType::Login => "login",
+            StorageType::GameSettings => "game",
+            StorageType::WineSettings => "wine",
+            StorageType::VersionSettings => "version",
+            StorageType::UpdateSettings => "update",
+            StorageType::Installations => "installations",
+            StorageType::Profiles => "profiles",
+            StorageType::HardwareFingerprint => "hardware",
+            StorageType::InterfaceSettings => "interface",
+
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("Unknown storage type: {:?}", self),
+        }
+    }
+}
+
+/// Resolves the path of a [StorageType] file inside the working directory, the extension is
+/// appended to the file name to resolve the temporary and backup files
+fn resolve_storage_path(
+    paths: &LauncherPaths,
+    storage_type: &StorageType,
+    extension: &str,
+) -> PathBuf {
+    paths
+        .working_directory()
+        .join(format!("{}.{}", storage_type.file_name(), extension))
+}
+
+/// The file a [StorageType] is saved to, which might not exist yet
+pub fn storage_path(paths: &LauncherPaths, storage_type: StorageType) -> PathBuf {
+    resolve_storage_path(paths, &storage_type, "json")
+}
+
+/// Saves data to a [StorageType] inside the working directory
+///
+/// The data is written to a temporary file first which then replaces the actual file, so a crash
+/// mid-write never leaves a truncated file behind. The previous file is kept as a backup if it
+/// still contains valid JSON
+///
+/// Fields of the previous file which the data doesn't know about are kept together with the newer
+/// schema version, so settings written by a newer launcher are never wiped by an older one
+pub fn save_storage_data<T: Serialize>(
+    paths: &LauncherPaths,
+    storage_type: StorageType,
+    data: T,
+) -> Result<(), StorageError> {
+    let mut content = match serde_json::to_value(&data) {
+        Ok(Value::Object(content)) => content,
+        _ => {
+            return Err(StorageError::JsonSerialize {
+                message: "storage-serialize",
+            })
+        }
+    };
+
+    let working_directory = paths.create_working_directory()?;
+    let storage_path = resolve_storage_path(paths, &storage_type, "json");
+    let temporary_path = resolve_storage_path(paths, &storage_type, "json.tmp");
+    let backup_path = resolve_storage_path(paths, &storage_type, "json.bak");
+
+    let previous_content = read_storage_object(&storage_path).ok();
+    let mut schema_version = storage_type.schema_version();
+    if let Some(ref previous_content) = previous_content {
+        for (key, value) in previous_content {
+            if !content.contains_key(key) {
+                content.insert(key.clone(), value.clone());
+            }
+        }
+
+        // A file written by a newer launcher keeps its version, otherwise that launcher would
+        // migrate the fields it wrote itself a second time
+        if let Some(previous_version) = previous_content
+            .get(SCHEMA_VERSION_FIELD)
+            .and_then(Value::as_u64)
+        {
+            schema_version = schema_version.max(previous_version);
+        }
+    }
+    content.insert(
+        SCHEMA_VERSION_FIELD.to_string(),
+        Value::from(schema_version),
+    );
+
+    let serialized = serde_json::to_string(&content).map_err(|_| StorageError::JsonSerialize {
+        message: "storage-serialize",
+    })?;
+
+    let mut temporary_file = File::create(temporary_path.as_path())
+        .map_err(|error| StorageError::io("storage-create-temporary", &temporary_path, error))?;
+    temporary_file
+        .write_all(serialized.as_bytes())
+        .and_then(|_| temporary_file.sync_all())
+        .map_err(|error| StorageError::io("storage-write-temporary", &temporary_path, error))?;
+
+    // Only a file which can still be parsed is worth keeping as the last good version
+    if previous_content.is_some() {
+        std::fs::copy(&storage_path, &backup_path)
+            .map_err(|error| StorageError::io("storage-back-up", &backup_path, error))?;
+    }
+
+    std::fs::rename(&temporary_path, &storage_path)
+        .map_err(|error| StorageError::io("storage-replace", &storage_path, error))?;
+
+    // The rename itself is only durable once the directory has been synced as well, this
+    // is not supported on every platform which is why failures are ignored
+    if let Ok(directory) = File::open(working_directory) {
+        let _ = directory.sync_all();
+    }
+
+    Ok(())
+}
+
+/// Loads storage data from a [StorageType] inside the working directory
+///
+/// If nothing has been saved yet the default is saved and returned, which is not an error. Files
+/// written in an older schema version are migrated first. If the file cannot be read or parsed
+/// the backup of the last good version is loaded instead, the [LoadSource] tells which one it was
+pub fn load_storage_data<T: serde::de::DeserializeOwned + Serialize>(
+    paths: &LauncherPaths,
+    storage_type: StorageType,
+    default: T,
+) -> Result<Loaded<T>, StorageError> {
+    let storage_path = resolve_storage_path(paths, &storage_type, "json");
+    let backup_path = resolve_storage_path(paths, &storage_type, "json.bak");
+
+    // A failed existence check is treated as existing so the actual read reports the error
+    if matches!(storage_path.try_exists(), Ok(false))
+        && matches!(backup_path.try_exists(), Ok(false))
+    {
+        save_storage_data(paths, storage_type, &default)?;
+        return Ok(Loaded {
+            data: default,
+            source: LoadSource::Default,
+        });
+    }
+
+    let error = match read_storage_file(&storage_type, &storage_path) {
+        Ok(data) => {
+            return Ok(Loaded {
+                data,
+                source: LoadSource::File,
+            })
+        }
+        Err(error) => error,
+    };
+
+    // The file is corrupted (most likely by an interrupted write), fall back to the last good version
+    match read_storage_file(&storage_type, &backup_path) {
+        Ok(data) => Ok(Loaded {
+            data,
+            source: LoadSource::Backup,
+        }),
+        Err(_) => Err(error),
+    }
+}
+
+/// Reads storage data from a [StorageType] inside the working directory
+///
+/// Unlike [load_storage_data] neither the backup nor the default is ever used, so the result
+/// always reflects what is actually written in the file
+pub fn read_storage_data<T: serde::de::DeserializeOwned>(
+    paths: &LauncherPaths,
+    storage_type: StorageType,
+) -> Result<T, StorageError> {
+    read_storage_file(
+        &storage_type,
+        &resolve_storage_path(paths, &storage_type, "json"),
+    )
+}
+
+/// Reads, migrates and parses a storage file
+fn read_storage_file<T: serde::de::DeserializeOwned>(
+    storage_type: &StorageType,
+    path: &Path,
+) -> Result<T, StorageError> {
+    let mut content = read_storage_object(path)?;
+    migration::migrate(storage_type, &mut content);
+    serde_json::from_value(Value::Object(content)).map_err(|_| StorageError::Parse {
+        message: "storage-deserialize",
+        path: path.to_path_buf(),
+    })
+}
+
+/// Reads the raw JSON object of a storage file
+fn read_storage_object(path: &Path) -> Result<Map<String, Value>, StorageError> {
+    let storage_file =
+        File::open(path).map_err(|error| StorageError::io("storage-open", path, error))?;
+
+    match serde_json::from_reader(BufReader::new(storage_file)) {
+        Ok(Value::Object(content)) => Ok(content),
+        _ => Err(StorageError::Parse {
+            message: "storage-invalid-object",
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+/// Calculates the size of a directory and all of its contents in bytes
+///
+/// Symbolic links are not followed, unreadable entries are skipped
+pub fn directory_size(path: &Path) -> u64 {
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        _ => return 0,
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Copies a directory and all of its contents, returns the amount of bytes copied
+///
+/// Symbolic links are skipped, the target directory is created if it doesn't exist yet
+pub fn copy_directory(from: &Path, to: &Path) -> std::io::Result<u64> {
+    std::fs::create_dir_all(to)?;
+
+    let mut copied = 0;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copied += copy_directory(&entry.path(), &target)?;
+        } else if file_type.is_file() {
+            copied += std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(copied)
+}
+
+/// Whether a name read from a file resolves to a folder directly inside another directory, it
+/// must never resolve to that directory itself or anything outside of it
+pub fn is_directory_name(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    )
+}
@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+// Every storage type uses `#[serde(default)]`, so fields missing from a file are filled with their
+// default instead of failing to parse the whole file and losing all other settings
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct LoginSettingData {
+    pub uid: i64,
+    pub remember_me: bool,
+}
+
+impl Default for LoginSettingData {
+    fn default() -> Self {
+        LoginSettingData {
+            uid: -1,
+            remember_me: true,
+        }
+    }
+}
+
+/// The memory (in MiB) of the game until a recommendation for the system has been stored
+pub const DEFAULT_MEMORY: i64 = 2048;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct GameSettingData {
+    /// The maximum memory (in MiB) of the game
+    pub memory: i64,
+    /// The memory (in MiB) the game starts with, left to Java if unset
+    pub min_memory: Option<i64>,
+    /// The minecraft directory chosen by the user, only applied after a restart
+    pub game_directory: Option<PathBuf>,
+    pub jvm_preset: JvmPreset,
+    /// Passed to Java after the arguments of the preset
+    pub jvm_arguments: Vec<String>,
+    pub window_width: Option<u32>,
+    pub window_height: Option<u32>,
+    pub fullscreen: bool,
+    /// A command the game is started through, like `gamemoderun` or `prime-run`
+    pub wrapper: Vec<String>,
+    /// Additional environment variables of the game process
+    pub environment: BTreeMap<String, String>,
+    /// Runs before the game is started, the game isn't started if it fails
+    pub pre_launch_hook: Option<String>,
+    /// Runs once the game has exited
+    pub post_exit_hook: Option<String>,
+}
+
+impl Default for GameSettingData {
+    fn default() -> Self {
+        GameSettingData {
+            memory: DEFAULT_MEMORY,
+            min_memory: None,
+            game_directory: None,
+            jvm_preset: JvmPreset::None,
+            jvm_arguments: Vec::new(),
+            window_width: None,
+            window_height: None,
+            fullscreen: false,
+            wrapper: Vec::new(),
+            environment: BTreeMap::new(),
+            pre_launch_hook: None,
+            post_exit_hook: None,
+        }
+    }
+}
+
+/// Tuned garbage collector flags the game can be started with
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, Eq, PartialEq)]
+pub enum JvmPreset {
+    #[default]
+    None,
+    G1,
+    Zgc,
+}
+
+impl JvmPreset {
+    /// The JVM arguments of the preset
+    pub fn arguments(&self) -> &'static [&'static str] {
+        match self {
+            JvmPreset::None => &[],
+            JvmPreset::G1 => &[
+                "-XX:+UnlockExperimentalVMOptions",
+                "-XX:+UseG1GC",
+                "-XX:G1NewSizePercent=20",
+                "-XX:G1ReservePercent=20",
+                "-XX:MaxGCPauseMillis=50",
+                "-XX:G1HeapRegionSize=32M",
+            ],
+            JvmPreset::Zgc => &["-XX:+UseZGC"],
+        }
+    }
+}
+
+/// Settings of the runner Windows-only components are started through on other systems
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WineSettingData {
+    pub enabled: bool,
+    pub runner: WineRunnerKind,
+    /// The wine binary or proton script, wine is looked up in the `PATH` if unset
+    pub binary: Option<PathBuf>,
+    /// The prefix directory, by default `wine-prefix` inside the working directory
+    pub prefix: Option<PathBuf>,
+    /// Whether proton translates Direct3D through the DXVK it ships with, wine uses whatever
+    /// has been installed into its prefix
+    pub dxvk: bool,
+    pub esync: bool,
+    /// Additional environment variables of every program started through the runner
+    pub environment: BTreeMap<String, String>,
+}
+
+/// The different runners Windows-only components can be started through
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, Eq, PartialEq)]
+pub enum WineRunnerKind {
+    #[default]
+    Wine,
+    Proton,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct VersionSettingData {
+    pub selections: Vec<VersionSelectionData>,
+}
+
+impl VersionSettingData {
+    /// Returns the selection of a channel, or the default selection if there is none yet
+    pub fn selection_for(&self, channel: &str) -> VersionSelectionData {
+        self.selections
+            .iter()
+            .find(|selection| selection.channel == channel)
+            .cloned()
+            .unwrap_or(VersionSelectionData {
+                channel: channel.to_string(),
+                ..VersionSelectionData::default()
+            })
+    }
+
+    /// Replaces the selection of a channel, or adds it if the channel has none yet
+    pub fn select(&mut self, selection: VersionSelectionData) {
+        match self
+            .selections
+            .iter_mut()
+            .find(|existing| existing.channel == selection.channel)
+        {
+            Some(existing) => *existing = selection,
+            None => self.selections.push(selection),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct VersionSelectionData {
+    pub channel: String,
+    pub preferred_version: String,
+    pub requires_latest: bool,
+}
+
+impl Default for VersionSelectionData {
+    fn default() -> Self {
+        VersionSelectionData {
+            channel: "".to_string(),
+            preferred_version: "".to_string(),
+            requires_latest: true,
+        }
+    }
+}
+
+/// The amount of installed versions kept per channel if the user hasn't configured it
+pub const DEFAULT_RETAINED_VERSIONS: usize = 3;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct UpdateSettingData {
+    pub poll_interval: u64,
+    pub pre_download: bool,
+    /// The amount of installed versions kept per channel
+    pub retained_versions: usize,
+}
+
+impl Default for UpdateSettingData {
+    fn default() -> Self {
+        UpdateSettingData {
+            poll_interval: 15,
+            pre_download: false,
+            retained_versions: DEFAULT_RETAINED_VERSIONS,
+        }
+    }
+}
+
+/// Settings of the launcher itself rather than of the game
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct InterfaceSettingData {
+    /// The locale like `de` or `de-AT` messages are shown in, the locale of the system if unset
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProfileSettingData {
+    pub profiles: Vec<InstanceProfileData>,
+}
+
+impl ProfileSettingData {
+    /// Finds a profile by its id
+    pub fn find(&self, id: &str) -> Option<&InstanceProfileData> {
+        self.profiles.iter().find(|profile| profile.id == id)
+    }
+
+    /// Finds a profile by its id to change it
+    pub fn find_mut(&mut self, id: &str) -> Option<&mut InstanceProfileData> {
+        self.profiles.iter_mut().find(|profile| profile.id == id)
+    }
+}
+
+/// A separate instance of the game with its own game directory and settings
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct InstanceProfileData {
+    /// Identifies the profile and names its instance directory, it never changes
+    pub id: String,
+    pub name: String,
+    pub selection: VersionSelectionData,
+    /// The memory (in MiB) the game is started with, the game settings are used if unset
+    pub memory: Option<i64>,
+    pub jvm_arguments: Vec<String>,
+    /// A custom game directory, by default every profile lives inside `instances/<id>`
+    pub game_directory: Option<PathBuf>,
+    /// Assets are taken from the main minecraft directory instead of being downloaded into
+    /// every instance again. Builds are started from a single jar, so there are no libraries
+    /// which could be shared as well
+    pub shared_assets: bool,
+}
+
+impl Default for InstanceProfileData {
+    fn default() -> Self {
+        InstanceProfileData {
+            id: "".to_string(),
+            name: "".to_string(),
+            selection: VersionSelectionData::default(),
+            memory: None,
+            jvm_arguments: Vec::new(),
+            game_directory: None,
+            shared_assets: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct InstallationData {
+    pub installations: Vec<InstalledVersionData>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct InstalledVersionData {
+    pub channel: String,
+    pub version: String,
+    pub last_updated: i64,
+    pub installed_at: i64,
+}
+
+/// The hardware of the last successful login, only salted hashes of it are ever stored
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HardwareFingerprintData {
+    /// Generated once per installation, so the hashes cannot be compared across machines
+    pub salt: String,
+    pub recorded_at: i64,
+    pub components: Vec<HardwareComponentData>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HardwareComponentData {
+    pub name: String,
+    /// The salted hash of the component, [None] if it could not be read
+    pub hash: Option<String>,
+}
@@ -0,0 +1,208 @@
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage::location::{version_folder, LauncherPaths};
+use crate::storage::types::InstallationData;
+use crate::storage::{StorageError, StorageType};
+
+/// The space which has to stay available after a download, running completely out of disk
+/// space breaks a lot more than just the launcher
+pub const RESERVED_SPACE: u64 = 256 * 1024 * 1024;
+
+/// Returned if a working directory does not have enough space available
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InsufficientSpaceError {
+    pub directory: PathBuf,
+    pub required: u64,
+    pub available: u64,
+}
+
+/// Checks if the filesystems holding the working directories can fit the given amount of bytes
+/// while still keeping the [RESERVED_SPACE] available
+///
+/// If the available space cannot be determined the check is skipped, we'd rather fail on
+/// write than refuse a download which would have fit. Directories which don't exist yet are
+/// checked on the filesystem they are going to be created on
+pub fn check_available_space(
+    paths: &LauncherPaths,
+    required: u64,
+) -> Result<(), InsufficientSpaceError> {
+    let required = required.saturating_add(RESERVED_SPACE);
+    for directory in [paths.minecraft_directory(), paths.working_directory()] {
+        let existing = match directory.ancestors().find(|ancestor| ancestor.exists()) {
+            Some(existing) => existing,
+            None => continue,
+        };
+        let available = match fs2::available_space(existing) {
+            Ok(available) => available,
+            _ => continue,
+        };
+
+        if available < required {
+            return Err(InsufficientSpaceError {
+                directory: directory.to_path_buf(),
+                required,
+                available,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// All categories the disk usage of the launcher is split into
+///
+/// The minecraft directory might be shared with the official launcher, only the versions
+/// recorded in the installations are known to belong to this launcher. Its libraries, assets
+/// and runtimes are reported as well, but never cleaned
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StorageCategory {
+    Versions,
+    Logs,
+    Caches,
+    Libraries,
+    Assets,
+    Runtimes,
+}
+
+impl StorageCategory {
+    pub const ALL: [StorageCategory; 6] = [
+        StorageCategory::Versions,
+        StorageCategory::Logs,
+        StorageCategory::Caches,
+        StorageCategory::Libraries,
+        StorageCategory::Assets,
+        StorageCategory::Runtimes,
+    ];
+
+    /// Whether the files of the category can be removed by [clean_category]
+    pub fn is_cleanable(&self) -> bool {
+        matches!(
+            self,
+            StorageCategory::Versions | StorageCategory::Logs | StorageCategory::Caches
+        )
+    }
+
+    /// Returns all directories belonging to the category
+    pub fn directories(
+        &self,
+        paths: &LauncherPaths,
+        installations: &InstallationData,
+    ) -> Vec<PathBuf> {
+        let working_directory = paths.working_directory();
+        match self {
+            StorageCategory::Versions => {
+                let mut directories: Vec<PathBuf> = installations
+                    .installations
+                    .iter()
+                    .filter(|installation| {
+                        crate::storage::is_directory_name(&version_folder(
+                            &installation.channel,
+                            &installation.version,
+                        ))
+                    })
+                    .map(|installation| {
+                        paths.version_directory(&installation.channel, &installation.version)
+                    })
+                    .collect();
+                directories.sort();
+                directories.dedup();
+                directories
+            }
+            StorageCategory::Logs => vec![working_directory.join("logs")],
+            StorageCategory::Caches => vec![working_directory.join("cache")],
+            StorageCategory::Libraries => vec![paths.minecraft_directory().join("libraries")],
+            StorageCategory::Assets => vec![paths.minecraft_directory().join("assets")],
+            StorageCategory::Runtimes => vec![paths.minecraft_directory().join("runtime")],
+        }
+    }
+}
+
+/// The disk usage of a single [StorageCategory]
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct CategoryUsage {
+    pub category: StorageCategory,
+    pub bytes: u64,
+    /// See [StorageCategory::is_cleanable]
+    pub cleanable: bool,
+}
+
+/// Calculates the disk usage of every [StorageCategory], versions which cannot be read from the
+/// installations are not counted
+pub fn storage_usage(paths: &LauncherPaths) -> Vec<CategoryUsage> {
+    let installations = crate::storage::load_storage_data(
+        paths,
+        StorageType::Installations,
+        InstallationData::default(),
+    )
+    .map(|loaded| loaded.data)
+    .unwrap_or_default();
+    StorageCategory::ALL
+        .iter()
+        .map(|category| CategoryUsage {
+            category: *category,
+            cleanable: category.is_cleanable(),
+            bytes: category
+                .directories(paths, &installations)
+                .iter()
+                .map(|directory| crate::storage::directory_size(directory.as_path()))
+                .sum(),
+        })
+        .collect()
+}
+
+/// Removes all files of a [StorageCategory] and returns the amount of bytes freed
+///
+/// Removed versions have to be installed again before they can be played, logs and caches are
+/// recreated once needed. Nothing is removed if the installations cannot be read, as there is no
+/// telling which versions belong to the launcher
+pub fn clean_category(
+    paths: &LauncherPaths,
+    category: StorageCategory,
+) -> Result<u64, StorageError> {
+    if !category.is_cleanable() {
+        return Err(StorageError::Shared {
+            message: "usage-shared-category",
+        });
+    }
+
+    let mut installations = crate::storage::load_storage_data(
+        paths,
+        StorageType::Installations,
+        InstallationData::default(),
+    )?
+    .data;
+
+    let mut freed = 0;
+    let mut result = Ok(());
+    for directory in category.directories(paths, &installations) {
+        if !directory.exists() {
+            continue;
+        }
+
+        let size = crate::storage::directory_size(&directory);
+        if let Err(error) = remove_dir_all(&directory) {
+            result = Err(StorageError::io(
+                "usage-remove-directory",
+                &directory,
+                error,
+            ));
+            break;
+        }
+        freed += size;
+    }
+
+    // Only the records of versions which are actually gone are dropped
+    if category == StorageCategory::Versions {
+        installations.installations.retain(|installation| {
+            paths
+                .version_directory(&installation.channel, &installation.version)
+                .exists()
+        });
+        crate::storage::save_storage_data(paths, StorageType::Installations, installations)?;
+    }
+
+    result.map(|_| freed)
+}
@@ -0,0 +1,45 @@
+//! Imports settings bundles handed out by other users
+
+use moon_launcher_core::settings::bundle::SettingsBundle;
+use moon_launcher_core::settings::SettingsDocument;
+
+fn arguments(arguments: &[&str]) -> Vec<String> {
+    arguments
+        .iter()
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+#[test]
+fn only_tuning_jvm_arguments_are_imported() {
+    let mut current = SettingsDocument::default();
+    current.game.jvm_arguments = arguments(&["-XX:+UseG1GC", "-javaagent:local.jar"]);
+
+    let bundle = SettingsBundle {
+        game: Some(serde_json::json!({
+            "jvm_arguments": [
+                "-XX:+AlwaysPreTouch",
+                "-XX:MaxGCPauseMillis=50",
+                "-Xss2M",
+                "-XX:OnError=sh -c evil",
+                "-XX:Flags=.hotspotrc",
+                "-Xbootclasspath/a:evil.jar",
+                "@evil.txt",
+                "-Dlog4j.configurationFile=http://evil",
+                "-agentpath:evil.so",
+            ]
+        })),
+        ..SettingsBundle::default()
+    };
+
+    let patch = bundle.into_patch(&current).unwrap();
+    assert_eq!(
+        patch.game.unwrap().jvm_arguments,
+        arguments(&[
+            "-XX:+AlwaysPreTouch",
+            "-XX:MaxGCPauseMillis=50",
+            "-Xss2M",
+            "-javaagent:local.jar",
+        ])
+    );
+}
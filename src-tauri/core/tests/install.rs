@@ -0,0 +1,80 @@
+//! Decides which versions have to be downloaded, inside a working directory of its own
+
+use std::path::Path;
+
+use moon_launcher_core::api::moon::auth::Channel;
+use moon_launcher_core::game::install::{is_version_installed, version_jar};
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{InstallationData, InstalledVersionData};
+
+/// Installs the given versions as `(version, last_updated)` of the release channel
+fn setup(name: &str, installed: &[(&str, i64)]) -> (LauncherPaths, InstallationData) {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("install")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    let paths = LauncherPaths::new(directory.join("working"), directory.join("minecraft"));
+
+    let mut installations = InstallationData::default();
+    for (version, last_updated) in installed {
+        let jar = version_jar(&paths, "release", version);
+        std::fs::create_dir_all(jar.parent().unwrap()).unwrap();
+        std::fs::write(&jar, "jar").unwrap();
+        installations.installations.push(InstalledVersionData {
+            channel: "release".to_string(),
+            version: version.to_string(),
+            last_updated: *last_updated,
+            installed_at: 0,
+        });
+    }
+    (paths, installations)
+}
+
+/// The release channel whose latest build of 2.0 has been uploaded at the given time
+fn channel(last_updated: i64) -> Channel {
+    serde_json::from_value(serde_json::json!({
+        "name": "release",
+        "description": "",
+        "rankRequired": "USER",
+        "latestVersion": "2.0",
+        "lastUpdated": last_updated,
+        "availableVersions": [],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn re_uploaded_latest_versions_are_outdated() {
+    let (paths, installations) = setup("latest", &[("2.0", 10)]);
+
+    assert!(is_version_installed(
+        &paths,
+        &installations,
+        &channel(10),
+        "2.0"
+    ));
+    assert!(!is_version_installed(
+        &paths,
+        &installations,
+        &channel(20),
+        "2.0"
+    ));
+}
+
+#[test]
+fn pinned_versions_are_reused() {
+    let (paths, installations) = setup("pinned", &[("1.0", 10)]);
+
+    assert!(is_version_installed(
+        &paths,
+        &installations,
+        &channel(20),
+        "1.0"
+    ));
+    assert!(!is_version_installed(
+        &paths,
+        &installations,
+        &channel(20),
+        "1.5"
+    ));
+}
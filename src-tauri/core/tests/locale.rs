@@ -0,0 +1,18 @@
+//! Hands the messages of the active locale to the gui
+
+use moon_launcher_core::locale::{apply_settings, messages};
+use moon_launcher_core::storage::types::InterfaceSettingData;
+
+#[test]
+fn messages_keep_the_placeholders_of_their_arguments() {
+    apply_settings(&InterfaceSettingData {
+        locale: Some("de".to_string()),
+    });
+
+    let messages = messages();
+    assert_eq!(messages["serial-retry"], "Erneut versuchen");
+    assert_eq!(
+        messages["rollback-done"],
+        "{$channel} verwendet jetzt {$version}"
+    );
+}
@@ -0,0 +1,94 @@
+//! Migrates storage files written by other launcher versions inside a working directory of its own
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{UpdateSettingData, DEFAULT_RETAINED_VERSIONS};
+use moon_launcher_core::storage::{
+    load_storage_data, save_storage_data, storage_path, LoadSource, StorageType,
+};
+
+/// Writes the raw content of the update settings as another launcher version would have
+fn setup(name: &str, content: Value) -> LauncherPaths {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("migration")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    let paths = LauncherPaths::new(directory.join("working"), directory.join("minecraft"));
+
+    std::fs::create_dir_all(paths.working_directory()).unwrap();
+    std::fs::write(
+        storage_path(&paths, StorageType::UpdateSettings),
+        content.to_string(),
+    )
+    .unwrap();
+    paths
+}
+
+fn stored(paths: &LauncherPaths) -> Value {
+    let content = std::fs::read(storage_path(paths, StorageType::UpdateSettings)).unwrap();
+    serde_json::from_slice(&content).unwrap()
+}
+
+#[test]
+fn unversioned_files_are_migrated() {
+    let paths = setup(
+        "unversioned",
+        json!({ "poll_interval": 30, "pre_download": true }),
+    );
+
+    let loaded = load_storage_data(
+        &paths,
+        StorageType::UpdateSettings,
+        UpdateSettingData::default(),
+    )
+    .unwrap();
+    assert_eq!(loaded.source, LoadSource::File);
+    assert_eq!(loaded.data.poll_interval, 30);
+    assert_eq!(loaded.data.retained_versions, DEFAULT_RETAINED_VERSIONS);
+
+    save_storage_data(&paths, StorageType::UpdateSettings, loaded.data).unwrap();
+    assert_eq!(stored(&paths)["schema_version"], 1);
+}
+
+#[test]
+fn migrated_files_keep_their_values() {
+    let paths = setup(
+        "versioned",
+        json!({ "schema_version": 1, "poll_interval": 30, "retained_versions": 5 }),
+    );
+
+    let loaded = load_storage_data(
+        &paths,
+        StorageType::UpdateSettings,
+        UpdateSettingData::default(),
+    )
+    .unwrap();
+    assert_eq!(loaded.data.retained_versions, 5);
+    assert!(!loaded.data.pre_download);
+}
+
+#[test]
+fn files_of_newer_launchers_keep_their_fields() {
+    let paths = setup(
+        "newer",
+        json!({ "schema_version": 7, "poll_interval": 30, "channels": ["beta"] }),
+    );
+
+    let mut settings = load_storage_data(
+        &paths,
+        StorageType::UpdateSettings,
+        UpdateSettingData::default(),
+    )
+    .unwrap()
+    .data;
+    settings.poll_interval = 60;
+    save_storage_data(&paths, StorageType::UpdateSettings, settings).unwrap();
+
+    let content = stored(&paths);
+    assert_eq!(content["schema_version"], 7);
+    assert_eq!(content["poll_interval"], 60);
+    assert_eq!(content["channels"], json!(["beta"]));
+}
@@ -0,0 +1,72 @@
+//! Loads the stub library in `proprietary-stub` through the C ABI, like development builds do
+
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use moon_launcher_core::proprietary::abi::{SERIAL_ABI_VERSION, SERIAL_OS_ERROR};
+use moon_launcher_core::proprietary::{fetch_serial, ProprietaryError, LIBRARY_OVERRIDE_VARIABLE};
+use moon_launcher_core::storage::location::LauncherPaths;
+
+/// Builds the stub into the temporary directory of this test, so the build never waits for the
+/// lock of the target directory the tests are run from
+fn build_stub() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("../proprietary-stub/Cargo.toml");
+    let target_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("proprietary-stub");
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(&target_directory)
+        .status()
+        .expect("cargo could not be started");
+    assert!(status.success(), "the stub library could not be built");
+
+    target_directory
+        .join("debug")
+        .join(format!("{DLL_PREFIX}launcher_lib_stub{DLL_SUFFIX}"))
+}
+
+/// The library is loaded once per process and the stub is configured through the environment,
+/// which is why every case runs inside this single test
+#[tokio::test]
+async fn stub_implements_the_serial_abi() {
+    std::env::set_var(LIBRARY_OVERRIDE_VARIABLE, build_stub());
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("proprietary-paths");
+    let paths = LauncherPaths::new(directory.join("working"), directory.join("minecraft"));
+
+    // A library speaking another ABI is refused and not kept loaded
+    std::env::set_var(
+        "MOON_STUB_ABI_VERSION",
+        (SERIAL_ABI_VERSION + 1).to_string(),
+    );
+    match fetch_serial(&paths).await {
+        Err(ProprietaryError::AbiMismatch {
+            expected, found, ..
+        }) => {
+            assert_eq!(expected, SERIAL_ABI_VERSION);
+            assert_eq!(found, SERIAL_ABI_VERSION + 1);
+        }
+        other => panic!("expected an ABI mismatch, got {other:?}"),
+    }
+    std::env::remove_var("MOON_STUB_ABI_VERSION");
+
+    assert_eq!(fetch_serial(&paths).await.unwrap(), "STUB-SERIAL-0000");
+
+    // Larger than the first buffer, so the serial is only returned after asking again
+    let long_serial = "S".repeat(1000);
+    std::env::set_var("MOON_STUB_SERIAL", &long_serial);
+    assert_eq!(fetch_serial(&paths).await.unwrap(), long_serial);
+    std::env::remove_var("MOON_STUB_SERIAL");
+
+    std::env::set_var("MOON_STUB_FAIL", "1");
+    match fetch_serial(&paths).await {
+        Err(ProprietaryError::SerialUnavailable { message, code }) => {
+            assert_eq!(code, SERIAL_OS_ERROR);
+            assert_eq!(message, "proprietary-serial-os");
+        }
+        other => panic!("expected the OS error code, got {other:?}"),
+    }
+    std::env::remove_var("MOON_STUB_FAIL");
+}
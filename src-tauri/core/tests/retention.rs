@@ -0,0 +1,118 @@
+//! Removes outdated versions from a working directory of its own
+
+use std::path::Path;
+use std::process::Child;
+
+use moon_launcher_core::game::install::{load_installations, version_jar};
+use moon_launcher_core::game::process::{ExitCallback, ProcessRegistry, RunningGame};
+use moon_launcher_core::game::retention::collect_old_versions;
+use moon_launcher_core::settings::SettingsDocument;
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{InstallationData, InstalledVersionData};
+use moon_launcher_core::storage::StorageType;
+
+/// Reports a fixed list of games as running
+struct FixedProcesses(Vec<RunningGame>);
+
+impl ProcessRegistry for FixedProcesses {
+    fn watch(&self, _game: Child, _channel: &str, _version: &str, _on_exit: Option<ExitCallback>) {
+        unimplemented!("no game is started by these tests")
+    }
+
+    fn running(&self) -> Vec<RunningGame> {
+        self.0.clone()
+    }
+}
+
+/// Installs the given versions as `(channel, version, installed_at)` and keeps one per channel
+fn setup(name: &str, installed: &[(&str, &str, i64)]) -> (LauncherPaths, SettingsDocument) {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("retention")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    let paths = LauncherPaths::new(directory.join("working"), directory.join("minecraft"));
+
+    let mut installations = InstallationData::default();
+    for (channel, version, installed_at) in installed {
+        let jar = version_jar(&paths, channel, version);
+        std::fs::create_dir_all(jar.parent().unwrap()).unwrap();
+        std::fs::write(&jar, "jar").unwrap();
+        installations.installations.push(InstalledVersionData {
+            channel: channel.to_string(),
+            version: version.to_string(),
+            last_updated: 0,
+            installed_at: *installed_at,
+        });
+    }
+    moon_launcher_core::storage::save_storage_data(
+        &paths,
+        StorageType::Installations,
+        installations,
+    )
+    .unwrap();
+
+    let mut settings = SettingsDocument::default();
+    settings.updates.retained_versions = 1;
+    (paths, settings)
+}
+
+fn recorded(paths: &LauncherPaths) -> Vec<(String, String)> {
+    load_installations(paths)
+        .unwrap()
+        .installations
+        .into_iter()
+        .map(|installation| (installation.channel, installation.version))
+        .collect()
+}
+
+#[test]
+fn versions_of_other_channels_keep_their_files() {
+    let (paths, settings) = setup(
+        "channels",
+        &[
+            ("release", "1.0", 1),
+            ("release", "2.0", 2),
+            ("beta", "1.0", 3),
+        ],
+    );
+
+    let report = collect_old_versions(&settings, &paths, &FixedProcesses(Vec::new())).unwrap();
+
+    assert_eq!(report.removed_versions.len(), 1);
+    assert_eq!(report.removed_versions[0].channel, "release");
+    assert_eq!(report.freed_bytes, 3);
+    assert!(!version_jar(&paths, "release", "1.0").exists());
+    assert!(version_jar(&paths, "beta", "1.0").exists());
+    assert_eq!(
+        recorded(&paths),
+        [
+            ("release".to_string(), "2.0".to_string()),
+            ("beta".to_string(), "1.0".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn running_versions_are_kept() {
+    let (paths, settings) = setup(
+        "running",
+        &[
+            ("release", "1.0", 1),
+            ("release", "2.0", 2),
+            ("release", "3.0", 3),
+        ],
+    );
+    let processes = FixedProcesses(vec![RunningGame {
+        pid: 1,
+        channel: "release".to_string(),
+        version: "1.0".to_string(),
+        started_at: 0,
+    }]);
+
+    let report = collect_old_versions(&settings, &paths, &processes).unwrap();
+
+    assert_eq!(report.removed_versions.len(), 1);
+    assert_eq!(report.removed_versions[0].version, "2.0");
+    assert!(version_jar(&paths, "release", "1.0").exists());
+    assert!(!version_jar(&paths, "release", "2.0").exists());
+}
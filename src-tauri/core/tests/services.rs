@@ -0,0 +1,85 @@
+//! Covers the in-memory services the launcher keeps for as long as it runs
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use moon_launcher_core::api::moon::auth::Channel;
+use moon_launcher_core::game::downloads::{DownloadService, DownloadTracker};
+use moon_launcher_core::game::process::{ProcessRegistry, ProcessService};
+use moon_launcher_core::session::{SessionService, SessionStore};
+
+fn channel(name: &str) -> Channel {
+    serde_json::from_value(serde_json::json!({
+        "name": name,
+        "description": "",
+        "rankRequired": "USER",
+        "latestVersion": "1.0",
+        "lastUpdated": 0,
+        "availableVersions": [],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn renewed_sessions_replace_the_channels() {
+    let session = SessionService::default();
+    assert!(!session.is_signed_in());
+
+    session.sign_in("token".to_string(), vec![channel("release")]);
+    session.sign_in("renewed".to_string(), vec![channel("beta")]);
+
+    assert_eq!(session.session_token(), "renewed");
+    assert!(session.channel("release").is_none());
+    assert!(session.channel("beta").is_some());
+}
+
+#[test]
+fn updates_are_only_notified_once() {
+    let downloads = DownloadService::default();
+
+    assert!(!downloads.is_notified("release", 1));
+    assert!(downloads.mark_notified("release", 1));
+    assert!(downloads.is_notified("release", 1));
+    assert!(!downloads.mark_notified("release", 1));
+    // Channels are tracked on their own
+    assert!(downloads.mark_notified("beta", 1));
+    // A newer update is notified again
+    assert!(downloads.mark_notified("release", 2));
+}
+
+#[test]
+fn installations_of_the_same_version_share_their_lock() {
+    let downloads = DownloadService::default();
+    let lock = downloads.version_lock("release", "1.0");
+    let _guard = lock.try_lock().unwrap();
+
+    assert!(downloads.version_lock("release", "1.0").try_lock().is_err());
+    assert!(downloads.version_lock("release", "1.1").try_lock().is_ok());
+    assert!(downloads.version_lock("beta", "1.0").try_lock().is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn exited_games_are_no_longer_running() {
+    let processes = ProcessService::default();
+    let (exited, on_exit) = mpsc::channel();
+    let game = Command::new("sleep").arg("0.2").spawn().unwrap();
+    let pid = game.id();
+
+    processes.watch(
+        game,
+        "release",
+        "1.0",
+        Some(Box::new(move || exited.send(()).unwrap())),
+    );
+    let running = processes.running();
+    assert_eq!(running.len(), 1);
+    assert_eq!(running[0].pid, pid);
+    assert_eq!(running[0].version, "1.0");
+    assert!(processes.is_running("release", "1.0"));
+    assert!(!processes.is_running("beta", "1.0"));
+
+    // The game is removed before the exit callback is called
+    on_exit.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert!(processes.running().is_empty());
+}
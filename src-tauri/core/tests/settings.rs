@@ -0,0 +1,98 @@
+//! Loads the settings inside a working directory of its own
+
+use std::path::Path;
+
+use moon_launcher_core::settings::{LoadIssue, SettingsService};
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{GameSettingData, LoginSettingData};
+use moon_launcher_core::storage::{
+    load_storage_data, save_storage_data, storage_path, LoadSource, StorageError, StorageType,
+};
+
+fn setup(name: &str) -> LauncherPaths {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("settings")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    LauncherPaths::new(directory.join("working"), directory.join("minecraft"))
+}
+
+#[test]
+fn missing_files_are_created_with_their_defaults() {
+    let paths = setup("missing");
+
+    let loaded =
+        load_storage_data(&paths, StorageType::Login, LoginSettingData::default()).unwrap();
+    assert_eq!(loaded.source, LoadSource::Default);
+    assert!(storage_path(&paths, StorageType::Login).exists());
+
+    let loaded =
+        load_storage_data(&paths, StorageType::Login, LoginSettingData::default()).unwrap();
+    assert_eq!(loaded.source, LoadSource::File);
+}
+
+#[test]
+fn broken_files_are_parse_errors() {
+    let paths = setup("parse");
+    std::fs::create_dir_all(paths.working_directory()).unwrap();
+    let path = storage_path(&paths, StorageType::Login);
+    std::fs::write(&path, "not json").unwrap();
+
+    match load_storage_data(&paths, StorageType::Login, LoginSettingData::default()) {
+        Err(StorageError::Parse {
+            path: error_path, ..
+        }) => assert_eq!(error_path, path),
+        result => panic!("expected a parse error, got {:?}", result),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn unreadable_files_are_io_errors() {
+    let paths = setup("io");
+    std::fs::create_dir_all(paths.working_directory()).unwrap();
+    let path = storage_path(&paths, StorageType::Login);
+    // A link to itself exists but can never be opened
+    std::os::unix::fs::symlink(&path, &path).unwrap();
+
+    match load_storage_data(&paths, StorageType::Login, LoginSettingData::default()) {
+        Err(StorageError::Io {
+            path: error_path, ..
+        }) => assert_eq!(error_path, path),
+        result => panic!("expected an I/O error, got {:?}", result),
+    }
+}
+
+#[test]
+fn broken_sections_are_reported_on_load() {
+    let paths = setup("issues");
+    let login = LoginSettingData {
+        uid: 42,
+        remember_me: true,
+    };
+    save_storage_data(&paths, StorageType::Login, login).unwrap();
+    save_storage_data(&paths, StorageType::Login, login).unwrap();
+    std::fs::write(storage_path(&paths, StorageType::Login), "{").unwrap();
+    std::fs::write(storage_path(&paths, StorageType::GameSettings), "[]").unwrap();
+
+    let settings = SettingsService::load(paths);
+    let issues = settings.load_issues();
+    assert_eq!(issues.len(), 2);
+    assert!(matches!(
+        issues[0],
+        LoadIssue::Restored {
+            storage_type: StorageType::Login
+        }
+    ));
+    assert!(matches!(
+        issues[1],
+        LoadIssue::Failed {
+            storage_type: StorageType::GameSettings,
+            cause: StorageError::Parse { .. }
+        }
+    ));
+
+    let document = settings.document();
+    assert_eq!(document.login.uid, 42);
+    assert_eq!(document.game.memory, GameSettingData::default().memory);
+}
@@ -0,0 +1,87 @@
+//! Saves and loads storage files inside a working directory of its own
+
+use std::path::{Path, PathBuf};
+
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::LoginSettingData;
+use moon_launcher_core::storage::{
+    load_storage_data, save_storage_data, storage_path, LoadSource, StorageType,
+};
+
+fn setup(name: &str) -> LauncherPaths {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("storage")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    LauncherPaths::new(directory.join("working"), directory.join("minecraft"))
+}
+
+/// The path of the storage file with another extension appended, like `login.json.bak`
+fn sibling(paths: &LauncherPaths, extension: &str) -> PathBuf {
+    let mut path = storage_path(paths, StorageType::Login).into_os_string();
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+fn login(uid: i64) -> LoginSettingData {
+    LoginSettingData {
+        uid,
+        remember_me: true,
+    }
+}
+
+#[test]
+fn saving_replaces_the_file_and_backs_up_the_previous_one() {
+    let paths = setup("replace");
+
+    save_storage_data(&paths, StorageType::Login, login(1)).unwrap();
+    assert!(!sibling(&paths, ".tmp").exists());
+    assert!(!sibling(&paths, ".bak").exists());
+
+    save_storage_data(&paths, StorageType::Login, login(2)).unwrap();
+    assert!(!sibling(&paths, ".tmp").exists());
+
+    let backup: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(sibling(&paths, ".bak")).unwrap()).unwrap();
+    assert_eq!(backup["uid"], 1);
+
+    let loaded =
+        load_storage_data(&paths, StorageType::Login, LoginSettingData::default()).unwrap();
+    assert_eq!(loaded.source, LoadSource::File);
+    assert_eq!(loaded.data.uid, 2);
+}
+
+#[test]
+fn broken_files_are_not_backed_up() {
+    let paths = setup("broken-backup");
+
+    save_storage_data(&paths, StorageType::Login, login(1)).unwrap();
+    save_storage_data(&paths, StorageType::Login, login(2)).unwrap();
+    std::fs::write(storage_path(&paths, StorageType::Login), "{\"uid\": 3").unwrap();
+    save_storage_data(&paths, StorageType::Login, login(4)).unwrap();
+
+    let backup: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(sibling(&paths, ".bak")).unwrap()).unwrap();
+    assert_eq!(backup["uid"], 1);
+}
+
+#[test]
+fn interrupted_writes_fall_back_to_the_backup() {
+    let paths = setup("interrupted");
+
+    save_storage_data(&paths, StorageType::Login, login(1)).unwrap();
+    save_storage_data(&paths, StorageType::Login, login(2)).unwrap();
+
+    // A crash mid-write leaves a truncated file behind, alongside the leftover temporary file
+    std::fs::write(
+        storage_path(&paths, StorageType::Login),
+        "{\"uid\": 2, \"rem",
+    )
+    .unwrap();
+    std::fs::write(sibling(&paths, ".tmp"), "{").unwrap();
+
+    let loaded =
+        load_storage_data(&paths, StorageType::Login, LoginSettingData::default()).unwrap();
+    assert_eq!(loaded.source, LoadSource::Backup);
+    assert_eq!(loaded.data.uid, 1);
+}
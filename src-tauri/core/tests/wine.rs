@@ -0,0 +1,151 @@
+//! Runs Windows programs through a fake `wine` script, which records how it has been started
+#![cfg(unix)]
+
+use std::collections::BTreeMap;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use moon_launcher_core::game::launch::{launch_version, LaunchOptions};
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::game::wine::WineRunner;
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::{WineRunnerKind, WineSettingData};
+
+/// Appends every argument and the environment of the runner to the file in `FAKE_WINE_LOG`
+const FAKE_WINE: &str = r#"#!/bin/sh
+for argument in "$@"; do
+    echo "argument=$argument" >> "$FAKE_WINE_LOG"
+done
+echo "WINEPREFIX=$WINEPREFIX" >> "$FAKE_WINE_LOG"
+echo "WINEESYNC=$WINEESYNC" >> "$FAKE_WINE_LOG"
+echo "STEAM_COMPAT_DATA_PATH=$STEAM_COMPAT_DATA_PATH" >> "$FAKE_WINE_LOG"
+echo "STEAM_COMPAT_CLIENT_INSTALL_PATH=$STEAM_COMPAT_CLIENT_INSTALL_PATH" >> "$FAKE_WINE_LOG"
+"#;
+
+/// A working directory of its own with the fake runner inside, so the tests never share files
+fn setup(name: &str) -> (LauncherPaths, PathBuf, PathBuf) {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("wine")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let binary = directory.join("wine");
+    std::fs::write(&binary, FAKE_WINE).unwrap();
+    std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let paths = LauncherPaths::new(directory.join("working"), directory.join("minecraft"));
+    (paths, binary, directory.join("wine.log"))
+}
+
+fn settings(binary: &Path, log: &Path, runner: WineRunnerKind) -> WineSettingData {
+    WineSettingData {
+        enabled: true,
+        runner,
+        binary: Some(binary.to_path_buf()),
+        esync: true,
+        environment: BTreeMap::from([("FAKE_WINE_LOG".to_string(), log.display().to_string())]),
+        ..WineSettingData::default()
+    }
+}
+
+fn recorded(log: &Path) -> Vec<String> {
+    std::fs::read_to_string(log)
+        .unwrap_or_default()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[tokio::test]
+async fn prefix_is_initialized_through_wineboot() {
+    let (paths, binary, log) = setup("prefix");
+    let runner =
+        WineRunner::from_settings(&paths, &settings(&binary, &log, WineRunnerKind::Wine)).unwrap();
+
+    runner.initialize_prefix().await.unwrap();
+
+    let prefix = paths.working_directory().join("wine-prefix");
+    assert!(prefix.is_dir());
+    assert_eq!(
+        recorded(&log),
+        [
+            "argument=wineboot".to_string(),
+            "argument=--init".to_string(),
+            format!("WINEPREFIX={}", prefix.display()),
+            "WINEESYNC=1".to_string(),
+            "STEAM_COMPAT_DATA_PATH=".to_string(),
+            "STEAM_COMPAT_CLIENT_INSTALL_PATH=".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn proton_runs_programs_inside_the_compatibility_data() {
+    let (paths, binary, log) = setup("proton");
+    let runner =
+        WineRunner::from_settings(&paths, &settings(&binary, &log, WineRunnerKind::Proton))
+            .unwrap();
+
+    let status = runner.command("tool.exe").status().unwrap();
+
+    assert!(status.success());
+    let prefix = paths.working_directory().join("wine-prefix");
+    let recorded = recorded(&log);
+    assert_eq!(
+        recorded[..5],
+        [
+            "argument=run".to_string(),
+            "argument=tool.exe".to_string(),
+            "WINEPREFIX=".to_string(),
+            "WINEESYNC=".to_string(),
+            format!("STEAM_COMPAT_DATA_PATH={}", prefix.display()),
+        ]
+    );
+    // Only an actual Steam installation is passed on, never the prefix
+    assert_ne!(
+        recorded[5],
+        format!("STEAM_COMPAT_CLIENT_INSTALL_PATH={}", prefix.display())
+    );
+}
+
+#[test]
+fn windows_hooks_keep_their_arguments() {
+    let (paths, binary, log) = setup("hook");
+    let runner =
+        WineRunner::from_settings(&paths, &settings(&binary, &log, WineRunnerKind::Wine)).unwrap();
+
+    let options = LaunchOptions {
+        memory: 1024,
+        min_memory: None,
+        jvm_arguments: Vec::new(),
+        game_directory: paths.minecraft_directory().to_path_buf(),
+        assets_directory: paths.minecraft_directory().join("assets"),
+        window_size: None,
+        fullscreen: false,
+        // Exits right away instead of starting java
+        wrapper: vec!["true".to_string()],
+        environment: BTreeMap::new(),
+        pre_launch_hook: Some(r#""C:/Program Files/tool.exe" --profile "a b""#.to_string()),
+        post_exit_hook: None,
+        wine: Some(runner),
+    };
+    launch_version(
+        &paths,
+        "release",
+        "1.0",
+        options,
+        &ProcessService::default(),
+    )
+    .unwrap();
+
+    let recorded = recorded(&log);
+    assert_eq!(
+        recorded[..3],
+        [
+            "argument=C:/Program Files/tool.exe".to_string(),
+            "argument=--profile".to_string(),
+            "argument=a b".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,54 @@
+//! Implements the ABI of the proprietary library without reading anything from the device
+//!
+//! Build it with `cargo build -p moon-proprietary-stub` and point `MOON_PROPRIETARY_LIBRARY` at
+//! the resulting library to run a development build of the launcher against it. The serial
+//! can be changed with `MOON_STUB_SERIAL`, setting `MOON_STUB_FAIL` makes every call fail with
+//! the OS error code and `MOON_STUB_ABI_VERSION` changes the reported ABI version
+
+#[allow(dead_code)]
+#[path = "../../core/src/proprietary/abi.rs"]
+mod abi;
+
+use abi::{
+    SERIAL_ABI_VERSION, SERIAL_BUFFER_TOO_SMALL, SERIAL_INVALID_ARGUMENT, SERIAL_OK,
+    SERIAL_OS_ERROR,
+};
+
+/// The serial returned if `MOON_STUB_SERIAL` is not set
+const DEFAULT_SERIAL: &str = "STUB-SERIAL-0000";
+
+#[no_mangle]
+pub extern "C" fn moon_serial_abi_version() -> u32 {
+    std::env::var("MOON_STUB_ABI_VERSION")
+        .ok()
+        .and_then(|version| version.parse().ok())
+        .unwrap_or(SERIAL_ABI_VERSION)
+}
+
+/// See `abi::FetchSerialFn`
+///
+/// # Safety
+/// `buffer` must be valid for writes of `capacity` bytes and `length` must be valid for a
+/// single write
+#[no_mangle]
+pub unsafe extern "C" fn moon_fetch_serial(
+    buffer: *mut u8,
+    capacity: usize,
+    length: *mut usize,
+) -> i32 {
+    if length.is_null() || (buffer.is_null() && capacity > 0) {
+        return SERIAL_INVALID_ARGUMENT;
+    }
+    if std::env::var_os("MOON_STUB_FAIL").is_some() {
+        return SERIAL_OS_ERROR;
+    }
+
+    let serial = std::env::var("MOON_STUB_SERIAL").unwrap_or_else(|_| DEFAULT_SERIAL.to_string());
+    *length = serial.len();
+    if serial.len() > capacity {
+        return SERIAL_BUFFER_TOO_SMALL;
+    }
+
+    std::ptr::copy_nonoverlapping(serial.as_ptr(), buffer, serial.len());
+    SERIAL_OK
+}
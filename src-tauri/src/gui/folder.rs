@@ -1,18 +1,46 @@
 use serde::{Deserialize, Serialize};
 
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::ProfileSettingData;
+
 /// Opens a given [DirectoryType] inside the operating systems file explorer
+///
+/// Directories which don't exist yet are not created just to be opened
 #[tauri::command]
-pub async fn open_directory_type(directory: DirectoryType) {
+pub async fn open_directory_type(
+    settings: tauri::State<'_, SettingsService>,
+    directory: DirectoryType,
+) -> Result<(), LauncherError> {
+    let paths = settings.paths();
     let folder_to_open = match directory {
-        DirectoryType::Minecraft => crate::storage::location::MINECRAFT_WORKING_DIRECTORY.clone(),
-        DirectoryType::Settings => crate::storage::location::MOON_WORKING_DIRECTORY.clone(),
+        DirectoryType::Minecraft => paths.minecraft_directory().to_path_buf(),
+        DirectoryType::Settings => paths.working_directory().to_path_buf(),
+        DirectoryType::Instance(id) => {
+            let profiles = settings.get::<ProfileSettingData>();
+            let profile = profiles.find(&id).ok_or(LauncherError::new(
+                "directory.unknown_profile",
+                "profile-unknown",
+            ))?;
+            moon_launcher_core::game::profile::instance_directory(paths, profile)
+        }
     };
-    open::that(folder_to_open).expect("Unable to open directory");
+
+    if !folder_to_open.is_dir() {
+        return Err(LauncherError::new("directory.missing", "directory-missing"));
+    }
+
+    open::that(&folder_to_open).map_err(|_| {
+        LauncherError::new("directory.open", "directory-open")
+            .with_detail(folder_to_open.display().to_string())
+    })
 }
 
 /// All different directory types which can be opened through tauri commands
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum DirectoryType {
     Minecraft,
     Settings,
+    /// The instance directory of the profile with the given id
+    Instance(String),
 }
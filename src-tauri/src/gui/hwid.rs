@@ -0,0 +1,36 @@
+use moon_launcher_core::api::moon::hwid::HwidResetResponseData;
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::serial::diagnostics::HardwareDiagnostics;
+use moon_launcher_core::session::{SessionService, SessionStore};
+use moon_launcher_core::settings::SettingsService;
+
+/// Lists which parts of the hardware changed since the last successful login, only salted
+/// hashes are compared so no hardware identifier ever leaves this device
+#[tauri::command]
+pub async fn hardware_diagnostics(
+    session: tauri::State<'_, SessionService>,
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<HardwareDiagnostics, LauncherError> {
+    Ok(moon_launcher_core::serial::diagnostics::diagnose(
+        settings.paths(),
+        &session.serial(),
+    )?)
+}
+
+/// Files a HWID reset request, the serial of this device becomes the new HWID once approved
+#[tauri::command]
+pub async fn request_hwid_reset(
+    session: tauri::State<'_, SessionService>,
+    uid: i64,
+) -> Result<HwidResetResponseData, LauncherError> {
+    Ok(moon_launcher_core::api::moon::hwid::request_hwid_reset(session.inner(), uid).await?)
+}
+
+/// Fetches the status of the latest HWID reset request
+#[tauri::command]
+pub async fn hwid_reset_status(
+    session: tauri::State<'_, SessionService>,
+    uid: i64,
+) -> Result<HwidResetResponseData, LauncherError> {
+    Ok(moon_launcher_core::api::moon::hwid::fetch_hwid_reset_status(session.inner(), uid).await?)
+}
@@ -0,0 +1,54 @@
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::game::retention::{CollectionReport, RollbackError};
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::{VersionSelectionData, VersionSettingData};
+
+/// Switches the selection of a channel to the version installed before the current one
+///
+/// The previous version is pinned, otherwise the next launch would simply use the latest
+/// version again
+#[tauri::command]
+pub async fn rollback_channel(
+    settings: tauri::State<'_, SettingsService>,
+    channel: String,
+) -> Result<VersionSelectionData, LauncherError> {
+    let selection = settings.get::<VersionSettingData>().selection_for(&channel);
+
+    let installations = moon_launcher_core::game::install::load_installations(settings.paths())?;
+    let previous = moon_launcher_core::game::retention::find_previous_installation(
+        settings.paths(),
+        &installations,
+        &selection,
+    )
+    .ok_or(RollbackError::NoPreviousVersion {
+        message: "rollback-no-previous-version",
+    })?;
+
+    let rolled_back = VersionSelectionData {
+        channel,
+        preferred_version: previous.version,
+        requires_latest: false,
+    };
+    settings
+        .update(|versions: &mut VersionSettingData| versions.select(rolled_back.clone()))
+        .map_err(|cause| RollbackError::SelectionSave {
+            message: "rollback-selection-save",
+            cause,
+        })?;
+
+    Ok(rolled_back)
+}
+
+/// Removes all installed versions beyond the retention limit and reports the freed disk space
+#[tauri::command]
+pub async fn collect_old_versions(
+    settings: tauri::State<'_, SettingsService>,
+    processes: tauri::State<'_, ProcessService>,
+) -> Result<CollectionReport, LauncherError> {
+    Ok(moon_launcher_core::game::retention::collect_old_versions(
+        &settings.document(),
+        settings.paths(),
+        processes.inner(),
+    )?)
+}
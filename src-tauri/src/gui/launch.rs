@@ -0,0 +1,45 @@
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::downloads::DownloadService;
+use moon_launcher_core::game::launch::{launch_selection, LaunchOptions};
+use moon_launcher_core::game::process::{ProcessRegistry, ProcessService, RunningGame};
+use moon_launcher_core::game::version::ResolvedVersion;
+use moon_launcher_core::session::SessionService;
+use moon_launcher_core::settings::SettingsService;
+
+/// Launches a channel using the version resolved from the users selection
+///
+/// The resolved version is installed first if it is missing, the returned [ResolvedVersion]
+/// contains a warning if the selection could not be honored
+#[tauri::command]
+pub async fn launch_channel(
+    session: tauri::State<'_, SessionService>,
+    downloads: tauri::State<'_, DownloadService>,
+    processes: tauri::State<'_, ProcessService>,
+    settings: tauri::State<'_, SettingsService>,
+    channel: String,
+) -> Result<ResolvedVersion, LauncherError> {
+    let paths = settings.paths();
+    let document = settings.document();
+    let selection = document.versions.selection_for(&channel);
+    let options = LaunchOptions::from_settings(paths, &document.game, &document.wine);
+
+    launch_selection(
+        session.inner(),
+        downloads.inner(),
+        processes.inner(),
+        &document,
+        paths,
+        &selection,
+        options,
+    )
+    .await
+    .map_err(LauncherError::from)
+}
+
+/// Lists all games started by the launcher which are still running
+#[tauri::command]
+pub async fn running_games(
+    processes: tauri::State<'_, ProcessService>,
+) -> Result<Vec<RunningGame>, LauncherError> {
+    Ok(processes.running())
+}
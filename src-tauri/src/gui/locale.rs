@@ -0,0 +1,28 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::locale::LocaleInfo;
+
+/// The messages the gui translates its own labels with
+#[derive(Clone, Debug, Serialize)]
+pub struct Translations {
+    pub locale: String,
+    pub messages: BTreeMap<String, String>,
+}
+
+/// Returns all locales the user can choose inside the interface settings
+#[tauri::command]
+pub async fn get_locales() -> Result<Vec<LocaleInfo>, LauncherError> {
+    Ok(moon_launcher_core::locale::locales())
+}
+
+/// Returns the messages of the active locale, which change once another locale is chosen
+#[tauri::command]
+pub async fn get_translations() -> Result<Translations, LauncherError> {
+    Ok(Translations {
+        locale: moon_launcher_core::locale::active_locale(),
+        messages: moon_launcher_core::locale::messages(),
+    })
+}
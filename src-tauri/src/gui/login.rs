@@ -1,60 +1,47 @@
-use tokio::sync::Mutex;
-
-use crate::api::moon::auth::{AuthenticationError, AuthenticationResponseData};
-use crate::gui::LauncherState;
-use crate::storage::types::LoginSettingData;
-use crate::storage::{StorageError, StorageType};
+use moon_launcher_core::api::moon::auth::AuthenticationResponseData;
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::session::{SessionService, SessionStore};
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::LoginSettingData;
 
 #[tauri::command]
 pub async fn load_login_settings(
-    state: tauri::State<'_, Mutex<LauncherState>>,
-) -> Result<LoginSettingData, StorageError> {
-    let mut state = state.lock().await;
-
-    // Load data if it is not present in state cache yet
-    match state.cached_login_data {
-        Some(data) => Ok(data),
-        None => {
-            let loaded_data = crate::storage::load_storage_data(
-                StorageType::Login,
-                LoginSettingData {
-                    uid: -1,
-                    remember_me: true,
-                },
-            )?;
-
-            state.cached_login_data = Some(loaded_data);
-            Ok(loaded_data)
-        }
-    }
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<LoginSettingData, LauncherError> {
+    Ok(settings.get())
 }
 
 #[tauri::command]
 pub async fn login(
-    state: tauri::State<'_, Mutex<LauncherState>>,
+    session: tauri::State<'_, SessionService>,
+    settings: tauri::State<'_, SettingsService>,
     uid: &str,
     remember_me: bool,
-) -> Result<AuthenticationResponseData, AuthenticationError> {
-    let mut state = state.lock().await;
+) -> Result<AuthenticationResponseData, LauncherError> {
     let uid_i = uid.parse::<i64>().unwrap_or(0);
-    let authentication_data = crate::api::moon::auth::authenticate(&state, uid_i).await;
+    let authentication_data =
+        moon_launcher_core::api::moon::auth::authenticate(session.inner(), uid_i).await;
 
-    // Update the session token if possible
+    // Update the session token and channels if possible
     if let Ok(ref data) = authentication_data {
-        state.session_token = data.session_key.clone()
+        session.sign_in(data.session_key.clone(), data.available_channels.clone());
+
+        // The fingerprint is only used to diagnose HWID mismatches later on, a login must
+        // never fail because of it
+        let _ = moon_launcher_core::serial::diagnostics::record_fingerprint(
+            settings.paths(),
+            &session.serial(),
+        );
     }
 
-    // Save the login preferences
-    let login_data = LoginSettingData {
-        uid: uid_i,
-        remember_me,
-    };
-    state.cached_login_data = Some(login_data);
-
-    #[allow(clippy::redundant_pattern_matching)]
-    if let Err(_) = crate::storage::save_storage_data(StorageType::Login, login_data) {
-        // TODO: please handle this error
-    }
+    // Save the login preferences, a failed login keeps the entered UID as well
+    let saved = settings.update(|login: &mut LoginSettingData| {
+        login.uid = uid_i;
+        login.remember_me = remember_me;
+    });
 
-    authentication_data
+    // Failing to log in is more important than failing to remember it
+    let authentication_data = authentication_data?;
+    saved?;
+    Ok(authentication_data)
 }
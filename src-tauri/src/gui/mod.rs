@@ -1,41 +1,55 @@
-use tauri::async_runtime::Mutex;
-
-use crate::storage::types::{GameSettingData, LoginSettingData, VersionSettingData};
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::memory::MemoryInfo;
+use moon_launcher_core::serial::SerialProvider;
+use moon_launcher_core::session::{SessionService, SessionStore};
 
 pub mod folder;
+pub mod hwid;
+pub mod install;
+pub mod launch;
+pub mod locale;
 pub mod login;
+pub mod profile;
 pub mod settings;
+pub mod update;
+pub mod usage;
+pub mod watcher;
+pub mod wine;
 
-/// Contains things required multiple times throughout the runtime process
-/// This struct is managed by gui and can be used in every gui command
-#[derive(Clone)]
-pub struct LauncherState {
-    pub serial: String,
-    pub session_token: String,
-    pub cached_login_data: Option<LoginSettingData>,
-    pub cached_game_state: Option<GameSettingData>,
-    pub cached_selection_state: Option<VersionSettingData>,
+/// Loads the serial of this device through the active serial provider
+///
+/// Everything besides logging in keeps working if this fails, so the user can still change
+/// settings and launch installed versions
+#[tauri::command]
+pub async fn load_serial(
+    session: tauri::State<'_, SessionService>,
+    provider: tauri::State<'_, Box<dyn SerialProvider>>,
+) -> Result<(), LauncherError> {
+    session.set_serial(provider.fetch_serial().await?);
+    Ok(())
 }
 
+/// Discards everything the serial provider has cached, like the downloaded proprietary
+/// library, and retries loading the serial
 #[tauri::command]
-pub async fn load_serial(state: tauri::State<'_, Mutex<LauncherState>>) -> Result<(), ()> {
-    let mut guard = state.lock().await;
-    let serial = unsafe {
-        match crate::proprietary::fetch_serial() {
-            Ok(serial) => serial,
-            _ => return Err(()),
-        }
-    };
-    println!("ok {}", serial);
-    guard.serial = serial;
-    Ok(())
+pub async fn retry_load_serial(
+    session: tauri::State<'_, SessionService>,
+    provider: tauri::State<'_, Box<dyn SerialProvider>>,
+) -> Result<(), LauncherError> {
+    provider.reset()?;
+    load_serial(session, provider).await
 }
 
+/// Describes the memory of the system in MiB and recommends the memory for a channel
 #[tauri::command]
-pub async fn get_max_available_memory() -> u64 {
-    let info = match sys_info::mem_info() {
-        Ok(info) => info,
-        Err(_) => return 0,
-    };
-    info.total
+pub async fn get_memory_info(
+    session: tauri::State<'_, SessionService>,
+    channel: Option<String>,
+) -> Result<MemoryInfo, LauncherError> {
+    let channel_minimum = channel
+        .and_then(|channel| session.channel(&channel))
+        .and_then(|channel| channel.minimum_memory);
+    Ok(moon_launcher_core::game::memory::memory_info(
+        channel_minimum,
+    ))
 }
@@ -0,0 +1,129 @@
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::downloads::DownloadService;
+use moon_launcher_core::game::launch::LaunchError;
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::game::version::ResolvedVersion;
+use moon_launcher_core::session::{SessionService, SessionStore};
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::{
+    GameSettingData, InstanceProfileData, ProfileSettingData,
+};
+
+/// Loads all profiles
+#[tauri::command]
+pub async fn list_profiles(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<Vec<InstanceProfileData>, LauncherError> {
+    Ok(settings.get::<ProfileSettingData>().profiles)
+}
+
+/// Creates a new profile for a channel
+#[tauri::command]
+pub async fn create_profile(
+    settings: tauri::State<'_, SettingsService>,
+    name: String,
+    channel: String,
+) -> Result<InstanceProfileData, LauncherError> {
+    Ok(moon_launcher_core::game::profile::create_profile(
+        &settings, &name, &channel,
+    )?)
+}
+
+/// Creates a copy of a profile including its worlds, options and mods
+#[tauri::command]
+pub async fn clone_profile(
+    settings: tauri::State<'_, SettingsService>,
+    id: String,
+    name: String,
+) -> Result<InstanceProfileData, LauncherError> {
+    Ok(moon_launcher_core::game::profile::clone_profile(
+        &settings,
+        settings.paths(),
+        &id,
+        &name,
+    )?)
+}
+
+/// Renames a profile
+#[tauri::command]
+pub async fn rename_profile(
+    settings: tauri::State<'_, SettingsService>,
+    id: String,
+    name: String,
+) -> Result<InstanceProfileData, LauncherError> {
+    Ok(moon_launcher_core::game::profile::rename_profile(
+        &settings, &id, &name,
+    )?)
+}
+
+/// Saves the settings of a profile, its memory has to satisfy the minimum of its channel
+#[tauri::command]
+pub async fn update_profile(
+    session: tauri::State<'_, SessionService>,
+    settings: tauri::State<'_, SettingsService>,
+    profile: InstanceProfileData,
+) -> Result<InstanceProfileData, LauncherError> {
+    let memory = profile
+        .memory
+        .unwrap_or_else(|| settings.get::<GameSettingData>().memory);
+    let channel_minimum = session
+        .channel(&profile.selection.channel)
+        .and_then(|channel| channel.minimum_memory);
+    moon_launcher_core::settings::validation::validate_channel_memory(memory, channel_minimum)?;
+
+    Ok(moon_launcher_core::game::profile::update_profile(
+        &settings, profile,
+    )?)
+}
+
+/// Deletes a profile, its instance directory is only removed if requested
+#[tauri::command]
+pub async fn delete_profile(
+    settings: tauri::State<'_, SettingsService>,
+    id: String,
+    delete_files: bool,
+) -> Result<(), LauncherError> {
+    Ok(moon_launcher_core::game::profile::delete_profile(
+        &settings,
+        settings.paths(),
+        &id,
+        delete_files,
+    )?)
+}
+
+/// Launches a profile inside its own instance directory
+#[tauri::command]
+pub async fn launch_profile(
+    session: tauri::State<'_, SessionService>,
+    downloads: tauri::State<'_, DownloadService>,
+    processes: tauri::State<'_, ProcessService>,
+    settings: tauri::State<'_, SettingsService>,
+    id: String,
+) -> Result<ResolvedVersion, LauncherError> {
+    let paths = settings.paths();
+    let document = settings.document();
+    let profile = document
+        .profiles
+        .find(&id)
+        .ok_or(LaunchError::UnknownProfile {
+            message: "profile-unknown",
+        })?;
+    let options = moon_launcher_core::game::profile::launch_options(
+        paths,
+        profile,
+        &document.game,
+        &document.wine,
+    );
+
+    moon_launcher_core::game::launch::launch_selection(
+        session.inner(),
+        downloads.inner(),
+        processes.inner(),
+        &document,
+        paths,
+        &profile.selection,
+        options,
+    )
+    .await
+    .map_err(LauncherError::from)
+}
@@ -1,162 +1,158 @@
-use crate::gui::LauncherState;
-use crate::storage::types::{GameSettingData, VersionSelectionData, VersionSettingData};
-use crate::storage::{StorageError, StorageType};
-use tokio::sync::Mutex;
+use std::path::PathBuf;
 
-/// Loads the game settings from the working directory
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::settings::bundle::{SettingChange, SettingsBundle};
+use moon_launcher_core::settings::{SettingsDocument, SettingsPatch, SettingsService};
+use moon_launcher_core::storage::types::{
+    GameSettingData, UpdateSettingData, VersionSelectionData, VersionSettingData,
+};
+use moon_launcher_core::storage::StorageType;
+
+/// Loads all settings at once
 #[tauri::command]
-pub async fn load_game_settings(
-    state: tauri::State<'_, Mutex<LauncherState>>,
-) -> Result<GameSettingData, StorageError> {
-    let mut state = state.lock().await;
+pub fn get_settings(settings: tauri::State<'_, SettingsService>) -> SettingsDocument {
+    settings.document()
+}
 
-    // Load data if it is not present in state cache yet
-    match state.cached_game_state {
-        Some(data) => Ok(data),
-        None => {
-            let loaded_data = crate::storage::load_storage_data(
-                StorageType::GameSettings,
-                GameSettingData { memory: 2048 },
-            )?;
+/// Describes the settings which have not been loaded from their own file on startup, so the
+/// user learns about them before changing anything
+#[tauri::command]
+pub fn get_settings_load_issues(settings: tauri::State<'_, SettingsService>) -> Vec<LauncherError> {
+    settings
+        .load_issues()
+        .iter()
+        .cloned()
+        .map(LauncherError::from)
+        .collect()
+}
 
-            state.cached_game_state = Some(loaded_data);
-            Ok(loaded_data)
-        }
-    }
+/// Replaces every settings section which is set inside the patch
+#[tauri::command]
+pub async fn patch_settings(
+    settings: tauri::State<'_, SettingsService>,
+    patch: SettingsPatch,
+) -> Result<SettingsDocument, LauncherError> {
+    Ok(settings.patch(patch)?)
 }
 
-/// Loads the selection settings from the working directory
+/// Loads the game settings
 #[tauri::command]
-pub async fn load_selection_settings(
-    state: tauri::State<'_, Mutex<LauncherState>>,
-) -> Result<VersionSettingData, StorageError> {
-    let mut state = state.lock().await;
+pub async fn load_game_settings(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<GameSettingData, LauncherError> {
+    Ok(settings.get())
+}
 
-    // Load data if it is not present in state cache yet
-    match state.cached_selection_state {
-        Some(ref data) => Ok(data.clone()),
-        None => {
-            let loaded_data = crate::storage::load_storage_data(
-                StorageType::VersionSettings,
-                VersionSettingData {
-                    selections: Vec::new(),
-                },
-            )?;
+/// Loads the update settings
+#[tauri::command]
+pub async fn load_update_settings(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<UpdateSettingData, LauncherError> {
+    Ok(settings.get())
+}
 
-            state.cached_selection_state = Some(loaded_data.clone());
-            Ok(loaded_data)
-        }
-    }
+/// Loads the selection settings
+#[tauri::command]
+pub async fn load_selection_settings(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<VersionSettingData, LauncherError> {
+    Ok(settings.get())
 }
 
-/// Loads the selection settings from the working directory
+/// Loads the selection settings of a single channel
 #[tauri::command]
 pub async fn load_selection_settings_for(
-    state: tauri::State<'_, Mutex<LauncherState>>,
+    settings: tauri::State<'_, SettingsService>,
     channel: String,
-) -> Result<VersionSelectionData, StorageError> {
-    let selection_settings = load_selection_settings(state)
-        .await
-        .unwrap_or(VersionSettingData {
-            selections: Vec::new(),
-        });
-
-    // Load selection data or fall-back to default
-    let selection_data = selection_settings
-        .selections
-        .into_iter()
-        .find(|selection| selection.channel == channel)
-        .unwrap_or(VersionSelectionData {
-            channel,
-            preferred_version: "".to_string(),
-            requires_latest: true,
-        });
-
-    Ok(selection_data)
+) -> Result<VersionSelectionData, LauncherError> {
+    Ok(settings.get::<VersionSettingData>().selection_for(&channel))
 }
 
-/// Saves the game settings to the working directory
+/// Saves the game settings, a changed game directory is only used after a restart
+///
+/// Returns a warning if the memory is valid but leaves too little for the rest of the system
 #[tauri::command]
 pub async fn save_game_settings(
-    state: tauri::State<'_, Mutex<LauncherState>>,
-    memory: i64,
-) -> Result<(), StorageError> {
-    let mut state = state.lock().await;
-    let game_settings = GameSettingData { memory };
-
-    // Save the data and store it in the launcher state
-    match crate::storage::save_storage_data(StorageType::GameSettings, game_settings) {
-        Ok(_) => {}
-        Err(error) => {
-            return Err(error);
-        }
-    };
-    state.cached_game_state = Some(game_settings);
+    settings: tauri::State<'_, SettingsService>,
+    game: GameSettingData,
+) -> Result<Option<String>, LauncherError> {
+    let saved = settings.update(|current: &mut GameSettingData| *current = game)?;
+    Ok(moon_launcher_core::game::memory::memory_warning(
+        saved.memory,
+    ))
+}
 
+/// Saves the update settings
+#[tauri::command]
+pub async fn save_update_settings(
+    settings: tauri::State<'_, SettingsService>,
+    poll_interval: u64,
+    pre_download: bool,
+    retained_versions: usize,
+) -> Result<(), LauncherError> {
+    settings.update(|updates: &mut UpdateSettingData| {
+        updates.poll_interval = poll_interval;
+        updates.pre_download = pre_download;
+        updates.retained_versions = retained_versions;
+    })?;
     Ok(())
 }
 
-/// Saves the game settings to the working directory
+/// Saves the selection settings of a single channel
 #[tauri::command]
 pub async fn save_selection_settings_for(
-    state: tauri::State<'_, Mutex<LauncherState>>,
+    settings: tauri::State<'_, SettingsService>,
     channel: String,
     version: String,
     always_latest: bool,
-) -> Result<(), StorageError> {
-    let mut state = state.lock().await;
-    let current_selection = state
-        .cached_selection_state
-        .clone()
-        .unwrap_or(VersionSettingData {
-            selections: Vec::new(),
-        });
+) -> Result<(), LauncherError> {
+    settings.update(|versions: &mut VersionSettingData| {
+        versions.select(VersionSelectionData {
+            channel,
+            preferred_version: version,
+            requires_latest: always_latest,
+        })
+    })?;
+    Ok(())
+}
 
-    // Update the selection data (don't ask what this is, but i call it the definition of shitty code)
-    let selections = current_selection.selections;
-    let mut new_selection_data = Vec::new();
-    let mut found_existing_channel = false;
-    for existing_selection in selections {
-        if existing_selection.channel == channel {
-            new_selection_data.push(VersionSelectionData {
-                channel: channel.clone(),
-                preferred_version: version.clone(),
-                requires_latest: always_latest,
-            });
-            found_existing_channel = true;
-        } else {
-            new_selection_data.push(VersionSelectionData {
-                channel: existing_selection.channel,
-                preferred_version: existing_selection.preferred_version,
-                requires_latest: existing_selection.requires_latest,
-            });
-        }
-    }
+/// Exports all settings into a single bundle file, the uid of the account is never exported
+#[tauri::command]
+pub async fn export_settings(
+    settings: tauri::State<'_, SettingsService>,
+    path: PathBuf,
+) -> Result<(), LauncherError> {
+    Ok(SettingsBundle::export(&settings.document()).write(&path)?)
+}
 
-    // Insert channel if not existing
-    if !found_existing_channel {
-        new_selection_data.push(VersionSelectionData {
-            channel: channel.clone(),
-            preferred_version: version.clone(),
-            requires_latest: always_latest,
-        });
-    }
+/// Lists all settings which would be changed by importing a bundle file without applying them
+#[tauri::command]
+pub async fn preview_settings_import(
+    settings: tauri::State<'_, SettingsService>,
+    path: PathBuf,
+) -> Result<Vec<SettingChange>, LauncherError> {
+    let current = settings.document();
+    let patch = SettingsBundle::read(&path)?.into_patch(&current)?;
+    Ok(moon_launcher_core::settings::bundle::diff_settings(
+        &current, &patch,
+    ))
+}
 
-    // Save the data and store it in the launcher state
-    state.cached_selection_state = Some(VersionSettingData {
-        selections: new_selection_data.clone(),
-    });
-    match crate::storage::save_storage_data(
-        StorageType::VersionSettings,
-        VersionSettingData {
-            selections: new_selection_data,
-        },
-    ) {
-        Ok(_) => {}
-        Err(error) => {
-            return Err(error);
-        }
-    };
+/// Imports a bundle file, either all of its settings are applied or none
+#[tauri::command]
+pub async fn import_settings(
+    settings: tauri::State<'_, SettingsService>,
+    path: PathBuf,
+) -> Result<SettingsDocument, LauncherError> {
+    let patch = SettingsBundle::read(&path)?.into_patch(&settings.document())?;
+    Ok(settings.patch(patch)?)
+}
 
-    Ok(())
+/// Resets all settings stored in a single storage file to their defaults
+#[tauri::command]
+pub async fn reset_settings(
+    settings: tauri::State<'_, SettingsService>,
+    storage_type: StorageType,
+) -> Result<SettingsDocument, LauncherError> {
+    Ok(settings.reset(storage_type)?)
 }
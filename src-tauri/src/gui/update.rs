@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::api::notification::Notification;
+use tauri::{AppHandle, Manager};
+
+use moon_launcher_core::api::moon::auth::renew_session;
+use moon_launcher_core::api::moon::auth::Channel;
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::downloads::{DownloadService, DownloadTracker};
+use moon_launcher_core::game::install::{install_version, is_latest_installed, load_installations};
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::session::{SessionService, SessionStore};
+use moon_launcher_core::settings::validation::MINIMUM_POLL_INTERVAL;
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::UpdateSettingData;
+
+/// The event emitted to every window once a new build is available
+pub const UPDATE_AVAILABLE_EVENT: &str = "update-available";
+
+/// The payload of the [UPDATE_AVAILABLE_EVENT]
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateAvailablePayload {
+    pub channel: String,
+    pub version: String,
+    pub last_updated: i64,
+    pub pre_downloaded: bool,
+}
+
+/// Spawns the background task which periodically checks all channels for new builds
+/// while the launcher is open
+pub fn spawn_update_poller(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let settings = app.state::<SettingsService>().get::<UpdateSettingData>();
+            let interval = settings.poll_interval.max(MINIMUM_POLL_INTERVAL);
+            tokio::time::sleep(Duration::from_secs(interval * 60)).await;
+
+            // Failed polls are simply retried on the next interval
+            let _ = poll_updates(&app).await;
+        }
+    });
+}
+
+/// Checks all channels for new builds right away instead of waiting for the next poll
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<(), LauncherError> {
+    poll_updates(&app).await
+}
+
+/// Re-fetches the channel data and notifies about every channel which has a newer build than
+/// the one installed
+///
+/// Channels with a pinned version are skipped, as the user explicitly chose not to update them
+async fn poll_updates(app: &AppHandle) -> Result<(), LauncherError> {
+    let service = app.state::<SettingsService>();
+    let paths = service.paths();
+    let settings = service.document();
+
+    let session = app.state::<SessionService>();
+    let downloads = app.state::<DownloadService>();
+    let processes = app.state::<ProcessService>();
+
+    // There is nothing to check as long as the user hasn't logged in yet
+    if !session.is_signed_in() {
+        return Ok(());
+    }
+    let uid = settings.login.uid;
+
+    // The channels are only part of the login response, downloads running in the meantime
+    // renew their session once it has been rejected
+    let channels = renew_session(session.inner(), uid).await?;
+
+    let installations = load_installations(paths)?;
+    for channel in channels {
+        let requires_latest = settings
+            .versions
+            .selection_for(&channel.name)
+            .requires_latest;
+
+        if !requires_latest || is_latest_installed(paths, &installations, &channel) {
+            continue;
+        }
+
+        // Only notify once for every update of a channel
+        if downloads.is_notified(&channel.name, channel.last_updated) {
+            continue;
+        }
+
+        // A failed pre-download is retried on the next poll before the user is notified
+        if settings.updates.pre_download
+            && install_version(
+                session.inner(),
+                downloads.inner(),
+                processes.inner(),
+                &settings,
+                paths,
+                &channel,
+                &channel.latest_version,
+            )
+            .await
+            .is_err()
+        {
+            continue;
+        }
+
+        // Another poll might have notified about the update while this one was downloading
+        if downloads.mark_notified(&channel.name, channel.last_updated) {
+            notify_update(app, &channel, settings.updates.pre_download);
+        }
+    }
+
+    Ok(())
+}
+
+/// Notifies the gui and the user about a new build of a channel
+fn notify_update(app: &AppHandle, channel: &Channel, pre_downloaded: bool) {
+    let payload = UpdateAvailablePayload {
+        channel: channel.name.clone(),
+        version: channel.latest_version.clone(),
+        last_updated: channel.last_updated,
+        pre_downloaded,
+    };
+    let _ = app.emit_all(UPDATE_AVAILABLE_EVENT, payload);
+
+    let key = if pre_downloaded {
+        "update-downloaded"
+    } else {
+        "update-available"
+    };
+    let arguments = [
+        ("channel", channel.name.clone()),
+        ("version", channel.latest_version.clone()),
+    ];
+    let _ = Notification::new(&app.config().tauri.bundle.identifier)
+        .title(moon_launcher_core::locale::translate("update-title", &[]))
+        .body(moon_launcher_core::locale::translate(key, &arguments))
+        .show();
+}
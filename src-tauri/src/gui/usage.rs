@@ -0,0 +1,25 @@
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::usage::{CategoryUsage, StorageCategory};
+
+/// Breaks the disk usage of the launcher down into its [StorageCategory]s
+#[tauri::command]
+pub async fn storage_usage(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<Vec<CategoryUsage>, LauncherError> {
+    Ok(moon_launcher_core::storage::usage::storage_usage(
+        settings.paths(),
+    ))
+}
+
+/// Removes all files of a [StorageCategory] and returns the amount of bytes freed
+#[tauri::command]
+pub async fn clean_storage_category(
+    settings: tauri::State<'_, SettingsService>,
+    category: StorageCategory,
+) -> Result<u64, LauncherError> {
+    Ok(moon_launcher_core::storage::usage::clean_category(
+        settings.paths(),
+        category,
+    )?)
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::{NamedStorage, StorageType};
+
+/// The event emitted to every window once a file edited outside of the launcher was rejected
+pub const SETTINGS_REJECTED_EVENT: &str = "settings-rejected";
+
+/// Editors and the launcher itself usually touch a file several times per save, all changes
+/// within this window are handled at once
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(250);
+
+/// The payload of the [SETTINGS_REJECTED_EVENT]
+#[derive(Clone, Debug, Serialize)]
+pub struct SettingsRejectedPayload {
+    pub file: String,
+    /// Already translated into the active locale
+    pub message: String,
+}
+
+/// Watches the working directory for storage files edited while the launcher is open
+///
+/// Changed files are reloaded into the [SettingsService], which notifies every window about the
+/// new settings. Files which cannot be parsed or fail validation are rejected and the launcher
+/// keeps using its current settings
+pub fn spawn_settings_watcher(app: AppHandle) -> notify::Result<()> {
+    let settings = app.state::<SettingsService>();
+    let working_directory = settings
+        .paths()
+        .create_working_directory()
+        .map_err(|error| {
+            notify::Error::generic(&moon_launcher_core::locale::translate(error.message(), &[]))
+        })?;
+
+    let (sender, receiver) = channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(working_directory, RecursiveMode::NonRecursive)?;
+
+    std::thread::spawn(move || {
+        // The watcher stops as soon as it is dropped, so it has to live as long as this thread
+        let _watcher = watcher;
+
+        let mut changed = HashSet::new();
+        loop {
+            let event = match receiver.recv_timeout(DEBOUNCE_DURATION) {
+                Ok(Ok(event)) => event,
+                Ok(Err(_)) => continue,
+                Err(RecvTimeoutError::Timeout) => {
+                    for storage_type in changed.drain() {
+                        reload_storage_file(&app, storage_type);
+                    }
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => return,
+            };
+
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                changed.extend(
+                    event
+                        .paths
+                        .iter()
+                        .filter_map(|path| StorageType::from_path(path)),
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Reloads a single storage file and tells the gui if it had to be rejected
+fn reload_storage_file(app: &AppHandle, storage_type: StorageType) {
+    if let Err(error) = app.state::<SettingsService>().reload(storage_type) {
+        let _ = app.emit_all(
+            SETTINGS_REJECTED_EVENT,
+            SettingsRejectedPayload {
+                file: format!("{}.json", storage_type.file_name()),
+                message: moon_launcher_core::locale::translate(error.message(), &[]),
+            },
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use moon_launcher_core::error::LauncherError;
+use moon_launcher_core::game::wine::{WineError, WineRunner};
+use moon_launcher_core::settings::SettingsService;
+use moon_launcher_core::storage::types::WineSettingData;
+
+/// Creates and initialises the wine prefix, returns the path of the prefix
+#[tauri::command]
+pub async fn initialize_wine_prefix(
+    settings: tauri::State<'_, SettingsService>,
+) -> Result<PathBuf, LauncherError> {
+    let runner = wine_runner(&settings)?;
+    runner.initialize_prefix().await?;
+    Ok(runner.prefix().to_path_buf())
+}
+
+fn wine_runner(settings: &SettingsService) -> Result<WineRunner, WineError> {
+    WineRunner::from_settings(settings.paths(), &settings.get::<WineSettingData>())
+}
@@ -1,35 +1,86 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::async_runtime::Mutex;
+use tauri::Manager;
 
-use crate::gui::LauncherState;
+use moon_launcher_core::game::downloads::DownloadService;
+use moon_launcher_core::game::process::ProcessService;
+use moon_launcher_core::session::SessionService;
+use moon_launcher_core::settings::{SettingsService, SETTINGS_CHANGED_EVENT};
+use moon_launcher_core::storage::location::LauncherPaths;
+use moon_launcher_core::storage::types::InterfaceSettingData;
 
-mod api;
 mod gui;
-mod proprietary;
-mod storage;
 
 fn main() {
+    // Nothing can be stored without a working directory, so there is no point in starting
+    let paths = LauncherPaths::resolve().expect("unable to resolve the launcher directories");
+
     tauri::Builder::default()
-        .manage(Mutex::new(LauncherState {
-            serial: "".to_string(),
-            session_token: "".to_string(),
-            cached_login_data: None,
-            cached_game_state: None,
-            cached_selection_state: None,
-        }))
+        .manage(SessionService::default())
+        .manage(DownloadService::default())
+        .manage(ProcessService::default())
+        .manage(moon_launcher_core::serial::active_provider(&paths))
+        .manage(SettingsService::load(paths))
+        .setup(|app| {
+            let settings = app.state::<SettingsService>();
+            moon_launcher_core::locale::apply_settings(&settings.get::<InterfaceSettingData>());
+
+            // Keep every window in sync no matter where a setting has been changed, the locale
+            // is applied first so the windows can load the new translations right away
+            let handle = app.handle();
+            settings.subscribe(Box::new(move |document| {
+                moon_launcher_core::locale::apply_settings(&document.interface);
+                let _ = handle.emit_all(SETTINGS_CHANGED_EVENT, document.clone());
+            }));
+            gui::update::spawn_update_poller(app.handle());
+
+            // The launcher works fine without noticing external edits, they are simply
+            // overwritten on the next save like before
+            let _ = gui::watcher::spawn_settings_watcher(app.handle());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             gui::login::login,
             gui::login::load_login_settings,
-            gui::get_max_available_memory,
+            gui::get_memory_info,
             gui::load_serial,
+            gui::retry_load_serial,
+            gui::hwid::hardware_diagnostics,
+            gui::hwid::request_hwid_reset,
+            gui::hwid::hwid_reset_status,
+            gui::settings::get_settings,
+            gui::settings::get_settings_load_issues,
+            gui::settings::patch_settings,
+            gui::settings::export_settings,
+            gui::settings::preview_settings_import,
+            gui::settings::import_settings,
+            gui::settings::reset_settings,
             gui::settings::load_game_settings,
             gui::settings::save_game_settings,
             gui::settings::load_selection_settings,
             gui::settings::load_selection_settings_for,
             gui::settings::save_selection_settings_for,
-            gui::folder::open_directory_type
+            gui::settings::load_update_settings,
+            gui::settings::save_update_settings,
+            gui::update::check_for_updates,
+            gui::folder::open_directory_type,
+            gui::launch::launch_channel,
+            gui::launch::running_games,
+            gui::locale::get_locales,
+            gui::locale::get_translations,
+            gui::profile::list_profiles,
+            gui::profile::create_profile,
+            gui::profile::clone_profile,
+            gui::profile::rename_profile,
+            gui::profile::update_profile,
+            gui::profile::delete_profile,
+            gui::profile::launch_profile,
+            gui::install::rollback_channel,
+            gui::install::collect_old_versions,
+            gui::usage::storage_usage,
+            gui::usage::clean_storage_category,
+            gui::wine::initialize_wine_prefix
         ])
         .run(tauri::generate_context!())
         .expect("error while running gui application");
@@ -0,0 +1,31 @@
+// Translations of the labels of the gui, taken from the same catalogs as the messages of errors
+import { derived, writable } from 'svelte/store';
+import { invoke } from '@tauri-apps/api/tauri';
+import { listen } from '@tauri-apps/api/event';
+
+/** Translations Interface | the messages of the active locale by their key */
+export interface Translations {
+	/** The locale the messages are taken from first, like `de` */
+	locale: string,
+	messages: Record<string, string>,
+}
+
+export const translations = writable<Translations>({ locale: 'en', messages: {} });
+
+/** Load the messages of the active locale, which changes whenever the interface settings change */
+export const loadTranslations = () =>
+	invoke('get_translations').then(loaded => translations.set(loaded as Translations));
+
+loadTranslations();
+listen('settings-changed', loadTranslations);
+
+/** Translate a label, the fallback is shown until the messages have been loaded */
+export const t = derived(translations, $translations =>
+	(key: string, fallback: string): string => $translations.messages[key] ?? fallback
+);
+
+/** Translate a message with arguments, placeholders like `{$channel}` are replaced by their argument */
+export const format = derived(t, $t =>
+	(key: string, fallback: string, args: Record<string, string | number>): string =>
+		$t(key, fallback).replace(/\{\$(\w+)\}/g, (placeholder, name) => name in args ? String(args[name]) : placeholder)
+);
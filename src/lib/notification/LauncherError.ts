@@ -0,0 +1,43 @@
+// Errors returned by the commands of the launcher
+import { Notification, NotificationType } from './NotificationHandler';
+
+/** Launcher Error Interface | every command rejects with one of these */
+export interface LauncherError {
+	/** Machine-readable code like `auth.hwid_mismatch`, never changed once released */
+	code: string,
+	/** Key of the message inside the catalogs of the launcher, like `auth-hwid-mismatch` */
+	key: string,
+	/** Arguments of the message and the detail */
+	arguments: Record<string, string>,
+	/** Message which can be displayed to the user, already translated into the active locale */
+	message: string,
+	/** Additional information like the file an error occurred on */
+	detail: string | null,
+	/** Error which caused this one */
+	cause: LauncherError | null,
+	/** Whether trying the same thing again might succeed */
+	retryable: boolean,
+}
+
+export const unknownErrorMessage = 'An unknown error occurred, please create an issue on GitHub';
+
+/** Check if a rejected command returned a Launcher Error */
+export const isLauncherError = (err: any): err is LauncherError =>
+	typeof err === 'object' && err !== null && typeof err.code === 'string' && typeof err.message === 'string';
+
+/** Check if a rejected command returned the Launcher Error with the given code */
+export const hasErrorCode = (err: any, code: string): boolean => isLauncherError(err) && err.code === code;
+
+/** Get the message of an error followed by the details of its whole cause chain */
+export const errorMessage = (err: any, fallback: string = unknownErrorMessage): string => {
+	if (!isLauncherError(err)) return fallback;
+	const details: string[] = [];
+	for (let error: LauncherError | null = err; error; error = error.cause) {
+		if (error.detail) details.push(error.detail);
+	}
+	return [err.message, ...details].join('\n\n');
+}
+
+/** Create the Notification rendering an error of a command */
+export const errorNotification = (title: string, err: any, duration: number = 5000, fallback: string = unknownErrorMessage): Notification =>
+	new Notification(title, errorMessage(err, fallback), NotificationType.Err, duration);